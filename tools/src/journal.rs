@@ -2,8 +2,97 @@ use clap::{Parser, Subcommand, Args as ClapArgs, ValueEnum};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use chrono::{SecondsFormat, Utc};
 
+/// User-level defaults so a user doesn't have to repeat `author set "..."`/`--editor`/`--dir`
+/// for every implementation directory. Loaded once at startup; CLI flags always win.
+mod config {
+    use serde::Deserialize;
+    use std::path::PathBuf;
+
+    #[derive(Debug, Default, Deserialize)]
+    pub struct Config {
+        pub default_author: Option<String>,
+        pub impl_root: Option<PathBuf>,
+        pub editor: Option<String>,
+    }
+
+    impl Config {
+        /// Looks for `<config_dir>/santa-journal/config.yaml`, falling back to
+        /// `config.toml` in the same directory. Missing files are not an error (an
+        /// unconfigured machine is the common case); a file that fails to parse is.
+        pub fn load() -> Result<Config, String> {
+            let Some(dirs) = directories::ProjectDirs::from("", "", "santa-journal") else {
+                return Ok(Config::default());
+            };
+            // `config_dir()` is already the app-specific directory (e.g. ~/.config/santa-journal);
+            // the config *file* still lives one level below that, not at the directory itself.
+            let base = dirs.config_dir();
+            for name in ["config.yaml", "config.toml"] {
+                let path = base.join(name);
+                if !path.exists() {
+                    continue;
+                }
+                let data = std::fs::read_to_string(&path)
+                    .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+                return if name.ends_with(".toml") {
+                    toml::from_str(&data).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+                } else {
+                    serde_yaml::from_str(&data).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+                };
+            }
+            Ok(Config::default())
+        }
+    }
+}
+
+/// Renders the seed text handed to $EDITOR when composing a new entry, so teams get
+/// consistently structured log records instead of a blank buffer.
+mod templates {
+    use super::Progress;
+    use std::path::Path;
+
+    const DEFAULT_ENTRY_TEMPLATE: &str = r#"## {{ date }}
+
+Author: {{ author }}
+
+Progress:
+{% for stage, status in progress %}- {{ stage }}: {{ status }}
+{% endfor %}
+{{ body }}"#;
+
+    /// Renders `templates/entry.md` under `dir` if present, otherwise the built-in
+    /// default, with `date`/`author`/`progress`/`body` in context. `progress` is a map
+    /// of stage name to its current status, e.g. `{"stage-1": "complete", ...}`; `body`
+    /// is the free-form text given on the command line, or empty when composing in
+    /// $EDITOR (where it's instead typed into the rendered seed text directly).
+    pub fn render_entry(dir: &Path, date: &str, author: &str, progress: &Progress, body: &str) -> Result<String, String> {
+        let path = dir.join("templates").join("entry.md");
+        let (source, label) = if path.exists() {
+            let data = std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            (data, path.display().to_string())
+        } else {
+            (DEFAULT_ENTRY_TEMPLATE.to_string(), "built-in default entry template".to_string())
+        };
+
+        let mut context = tera::Context::new();
+        context.insert("date", date);
+        context.insert("author", author);
+        context.insert("body", body);
+        context.insert("progress", &[
+            ("stage-1", &progress.stage_1),
+            ("stage-2", &progress.stage_2),
+            ("stage-3", &progress.stage_3),
+            ("stage-4", &progress.stage_4),
+            ("stage-5", &progress.stage_5),
+        ].into_iter().collect::<std::collections::BTreeMap<_, _>>());
+
+        tera::Tera::one_off(&source, &context, false).map_err(|e| format!("Failed to render {}: {}", label, e))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct JournalFile {
     author: String,
@@ -11,6 +100,10 @@ struct JournalFile {
     progress: Progress,
     #[serde(rename = "journal")]
     journal: Vec<JournalEntry>,
+    #[serde(default)]
+    metrics: Vec<MetricEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    drafts: Vec<Draft>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -36,6 +129,26 @@ struct JournalEntry {
     entry: String,
 }
 
+/// An in-progress entry not yet appended to `journal`. Ids are stable across runs so
+/// `draft edit`/`draft finish` keep working between invocations.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct Draft {
+    id: u32,
+    text: String,
+}
+
+/// One timed stage run recorded by a harness. santa-bootstrap creates the empty
+/// `metrics` array and aggregates it; santa-journal only needs to round-trip it
+/// without losing data.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct MetricEntry {
+    stage: String,
+    started_at: String,
+    finished_at: String,
+    duration_secs: f64,
+    outcome: String,
+}
+
 #[derive(Parser)]
 #[command(name = "santa-journal", about = "Interact with an implementation's JOURNAL file")]
 #[command(version = "0.1.0")]
@@ -50,6 +163,21 @@ Subcommands:
   - progress         Show all stage statuses, show one stage, or set a stage status
   - entry            Append a free-form journal entry with a timestamp
   - entries          List all entries in reverse chronological order
+  - draft            Stage an entry (new/list/edit) and append it to journal when ready (finish)
+  - status           Report whether the implementation directory's git working tree is dirty
+
+Pass --commit to have author set, progress set, entry and draft finish each create a git
+commit recording the change (off by default); --no-commit restores the default explicitly.
+
+The author, the folder searched for the newest implementation when --dir is not given,
+and --editor can be set once in a config file at
+<config dir>/santa-journal/config.yaml (or config.toml): default_author, impl_root, editor.
+CLI flags always override the config file.
+
+`entry` always renders templates/entry.md in the implementation directory (or a
+built-in default) with {{ date }}, {{ author }}, {{ progress }} and {{ body }}
+placeholders filled in. With no text this is the seed opened in $EDITOR ({{ body }}
+empty); with text given on the command line, the rendered result becomes the entry.
 
 Examples:
   santa-journal author
@@ -58,13 +186,34 @@ Examples:
   santa-journal progress stage-2
   santa-journal progress stage-3 set in-progress
   santa-journal entry "Finished stage-1 lexer"
+  santa-journal entry                         # composes the entry in $EDITOR from templates/entry.md
   santa-journal entries
+  santa-journal entries --format markdown
+  santa-journal entries --format json
+  santa-journal draft new "Working on the parser"
+  santa-journal draft list
+  santa-journal draft edit 0                  # re-opens the draft in $EDITOR
+  santa-journal draft finish 0
+  santa-journal --commit entry "Finished stage-1 lexer"
+  santa-journal status
 "#)]
 struct Cli {
     /// Path to the implementation directory (containing JOURNAL). Defaults to latest under impl/.
     #[arg(short, long, help = "Path to implementation dir (with JOURNAL). Defaults to newest under impl/.")]
     dir: Option<PathBuf>,
 
+    /// Editor to launch for `entry` with no text. Falls back to the config file's `editor`, then $VISUAL, then $EDITOR, then /usr/bin/editor.
+    #[arg(long, help = "Editor to launch for `entry` with no text (overrides $VISUAL/$EDITOR)")]
+    editor: Option<String>,
+
+    /// Create a git commit for each mutating command (author set, progress set, entry, draft finish). Off by default.
+    #[arg(long, overrides_with = "no_commit", help = "Commit JOURNAL changes made by mutating commands")]
+    commit: bool,
+
+    /// Disables --commit. Only useful to override a default set some other way (e.g. a future config file).
+    #[arg(long, overrides_with = "commit", help = "Do not commit JOURNAL changes (default)")]
+    no_commit: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -78,7 +227,11 @@ enum Commands {
     /// Append a journal entry
     Entry(EntryCmd),
     /// List entries in reverse chronological order
-    Entries,
+    Entries(EntriesCmd),
+    /// Stage an entry and append it to journal once finished
+    Draft(DraftCmd),
+    /// Report whether the implementation directory's git working tree is dirty
+    Status,
 }
 
 #[derive(ClapArgs)]
@@ -126,17 +279,55 @@ struct ProgressCmd {
 
 #[derive(ClapArgs)]
 struct EntryCmd {
-    #[arg(help = "Free-form text to append to JOURNAL with a timestamp.")]
-    text: String,
+    #[arg(help = "Free-form text to append to JOURNAL with a timestamp. Omit to compose it in $EDITOR.")]
+    text: Option<String>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+enum EntriesFormat {
+    /// `- <timestamp>\n  <entry>` per entry (current behavior)
+    Plain,
+    /// A single Markdown document, one `## <timestamp>` section per entry
+    Markdown,
+    /// A JSON array of `{written_at, entry}` objects
+    Json,
+}
+
+#[derive(ClapArgs)]
+struct EntriesCmd {
+    #[arg(long, value_enum, default_value = "plain", help = "Output format: plain | markdown | json")]
+    format: EntriesFormat,
+}
+
+#[derive(ClapArgs)]
+struct DraftCmd {
+    #[command(subcommand)]
+    sub: DraftSub,
+}
+
+#[derive(Subcommand)]
+enum DraftSub {
+    /// Start a new draft (allocates the lowest unused id)
+    New { text: String },
+    /// List all drafts by id and first line
+    List,
+    /// Re-open a draft's text in $EDITOR
+    Edit { id: u32 },
+    /// Move a draft's text into journal with a fresh timestamp
+    Finish { id: u32 },
 }
 
-fn resolve_impl_dir(explicit: &Option<PathBuf>) -> Result<PathBuf, String> {
+fn resolve_impl_dir(explicit: &Option<PathBuf>, config_impl_root: Option<&Path>) -> Result<PathBuf, String> {
     if let Some(dir) = explicit {
         return Ok(dir.clone());
     }
-    let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
-    let repo_root = if cwd.ends_with("tools") { cwd.parent().unwrap().to_path_buf() } else { cwd.clone() };
-    let impl_dir = repo_root.join("impl");
+    let impl_dir = if let Some(root) = config_impl_root {
+        root.to_path_buf()
+    } else {
+        let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
+        let repo_root = if cwd.ends_with("tools") { cwd.parent().unwrap().to_path_buf() } else { cwd.clone() };
+        repo_root.join("impl")
+    };
     let mut newest: Option<(PathBuf, std::time::SystemTime)> = None;
     for entry in fs::read_dir(&impl_dir).map_err(|e| format!("Failed to read {}: {}", impl_dir.display(), e))? {
         let entry = entry.map_err(|e| e.to_string())?;
@@ -162,10 +353,49 @@ fn read_journal(dir: &Path) -> Result<JournalFile, String> {
     serde_json::from_str(&data).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
 }
 
-fn write_journal(dir: &Path, jf: &JournalFile) -> Result<(), String> {
+/// Writes JOURNAL, then, when `commit_message` is given, stages the JOURNAL path and
+/// commits the index (including anything already staged) in the enclosing git repo
+/// (found by walking up from `dir`). Failing to
+/// find a repo is reported as an error, not silently ignored, since `--commit` was asked for.
+fn write_journal(dir: &Path, jf: &JournalFile, commit_message: Option<&str>) -> Result<(), String> {
     let path = dir.join("JOURNAL");
     let s = serde_json::to_string_pretty(jf).map_err(|e| e.to_string())?;
-    fs::write(&path, s).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    fs::write(&path, s).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    if let Some(message) = commit_message {
+        commit_journal_change(&path, &jf.author, message)?;
+    }
+    Ok(())
+}
+
+/// Stages `journal_path` and creates a commit with `message`, authored as `author` (falling
+/// back to the repo's configured git identity, then to a generic placeholder).
+fn commit_journal_change(journal_path: &Path, author: &str, message: &str) -> Result<(), String> {
+    let repo = git2::Repository::discover(journal_path)
+        .map_err(|e| format!("--commit was given but no git repository was found: {}", e))?;
+    let workdir = repo.workdir().ok_or("--commit was given but the repository has no working directory (bare repo)")?;
+    let relative_path = journal_path.strip_prefix(workdir)
+        .map_err(|_| "JOURNAL path is outside the repository working directory".to_string())?;
+
+    let mut index = repo.index().map_err(|e| e.to_string())?;
+    index.add_path(relative_path).map_err(|e| e.to_string())?;
+    index.write().map_err(|e| e.to_string())?;
+    let tree_id = index.write_tree().map_err(|e| e.to_string())?;
+    let tree = repo.find_tree(tree_id).map_err(|e| e.to_string())?;
+
+    let signature = if author.trim().is_empty() {
+        repo.signature()
+    } else {
+        git2::Signature::now(author, "journal@santa-lang-workshop.local")
+    }.or_else(|_| git2::Signature::now("santa-journal", "journal@santa-lang-workshop.local"))
+        .map_err(|e| e.to_string())?;
+
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+
+    repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+        .map_err(|e| e.to_string())?;
+    Ok(())
 }
 
 fn stage_get<'a>(p: &'a Progress, s: Stage) -> &'a str {
@@ -192,6 +422,56 @@ fn status_to_str(s: StatusVal) -> &'static str {
     match s { StatusVal::NotStarted => "not-started", StatusVal::InProgress => "in-progress", StatusVal::Complete => "complete" }
 }
 
+/// Picks the editor to launch for an empty `entry`: the explicit `--editor` flag, then
+/// the config file's `editor` key, then $VISUAL, then $EDITOR, then /usr/bin/editor.
+fn resolve_editor(explicit: &Option<String>, config_editor: Option<&str>) -> Result<String, String> {
+    explicit.clone()
+        .or_else(|| config_editor.map(str::to_string))
+        .or_else(|| std::env::var("VISUAL").ok())
+        .or_else(|| std::env::var("EDITOR").ok())
+        .filter(|e| !e.trim().is_empty())
+        .or_else(|| Path::new("/usr/bin/editor").exists().then(|| "/usr/bin/editor".to_string()))
+        .ok_or_else(|| "No editor configured. Set --editor, the config file, $VISUAL or $EDITOR.".to_string())
+}
+
+/// Opens `editor` on a temp `.md` file seeded with `initial`, waits for it to exit, and
+/// returns the contents with trailing newlines removed. Aborts (without touching JOURNAL) on a non-zero exit or
+/// an editor that couldn't be launched; the temp file is removed before returning either way.
+fn compose_entry_via_editor(editor: &str, initial: &str) -> Result<String, String> {
+    let path = std::env::temp_dir().join(format!("santa-journal-entry-{}.md", std::process::id()));
+    fs::write(&path, initial).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    let result = (|| -> Result<String, String> {
+        let status = Command::new(editor)
+            .arg(&path)
+            .status()
+            .map_err(|e| format!("Failed to launch editor '{}': {}", editor, e))?;
+        if !status.success() {
+            return Err(format!("Editor '{}' exited with a non-zero status; JOURNAL was not changed.", editor));
+        }
+        let contents = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Ok(contents.trim_end_matches('\n').to_string())
+    })();
+
+    let _ = fs::remove_file(&path);
+    result
+}
+
+/// Cap on the number of drafts, so a runaway drafting session can't grow JOURNAL without bound.
+const MAX_DRAFTS: usize = 1000;
+
+/// Lowest id not already used by an existing draft; fails once MAX_DRAFTS drafts exist.
+fn allocate_draft_id(drafts: &[Draft]) -> Result<u32, String> {
+    if drafts.len() >= MAX_DRAFTS {
+        return Err(format!("Draft limit reached ({}). Finish or remove an existing draft first.", MAX_DRAFTS));
+    }
+    let mut id = 0u32;
+    while drafts.iter().any(|d| d.id == id) {
+        id += 1;
+    }
+    Ok(id)
+}
+
 fn print_progress_table(p: &Progress) {
     println!("Stage     Status");
     println!("--------  -----------");
@@ -204,7 +484,9 @@ fn print_progress_table(p: &Progress) {
 
 fn main() -> Result<(), String> {
     let cli = Cli::parse();
-    let dir = resolve_impl_dir(&cli.dir)?;
+    let config = config::Config::load()?;
+    let dir = resolve_impl_dir(&cli.dir, config.impl_root.as_deref())?;
+    let commit = cli.commit;
 
     match cli.command {
         Commands::Author(cmd) => {
@@ -215,12 +497,19 @@ fn main() -> Result<(), String> {
                         return Err("Author is already set. Use the existing value or edit JOURNAL manually if needed.".to_string());
                     }
                     jf.author = name;
-                    write_journal(&dir, &jf)?;
+                    let message = format!("journal: set author \"{}\"", jf.author);
+                    write_journal(&dir, &jf, commit.then_some(message.as_str()))?;
                     println!("Author set.");
                 }
                 None => {
                     if jf.author.trim().is_empty() {
-                        println!("Author is not set yet. Use: santa-journal author set \"<elf-name>\"");
+                        if let Some(default_author) = &config.default_author {
+                            jf.author = default_author.clone();
+                            write_journal(&dir, &jf, commit.then_some(format!("journal: set author \"{}\"", jf.author).as_str()))?;
+                            println!("Author set from config default: {}", jf.author);
+                        } else {
+                            println!("Author is not set yet. Use: santa-journal author set \"<elf-name>\"");
+                        }
                     } else {
                         println!("Author: {}", jf.author);
                     }
@@ -246,7 +535,9 @@ fn main() -> Result<(), String> {
                         return Err(format!("Unknown action '{}'. Did you mean 'set'?", action));
                     }
                     stage_set(&mut jf.progress, *stage, status_to_str(*status));
-                    write_journal(&dir, &jf)?;
+                    let key = match stage { Stage::Stage1 => "stage-1", Stage::Stage2 => "stage-2", Stage::Stage3 => "stage-3", Stage::Stage4 => "stage-4", Stage::Stage5 => "stage-5" };
+                    let message = format!("journal: set {} {}", key, status_to_str(*status));
+                    write_journal(&dir, &jf, commit.then_some(message.as_str()))?;
                     println!("Updated.");
                 }
                 // invalid combinations
@@ -258,22 +549,141 @@ fn main() -> Result<(), String> {
         Commands::Entry(cmd) => {
             let mut jf = read_journal(&dir)?;
             let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
-            jf.journal.push(JournalEntry { written_at: now, entry: cmd.text });
-            write_journal(&dir, &jf)?;
+            let text = match cmd.text {
+                Some(text) => templates::render_entry(&dir, &now, &jf.author, &jf.progress, &text)?,
+                None => {
+                    let editor = resolve_editor(&cli.editor, config.editor.as_deref())?;
+                    let seed = templates::render_entry(&dir, &now, &jf.author, &jf.progress, "")?;
+                    let text = compose_entry_via_editor(&editor, &seed)?;
+                    if text.trim().is_empty() {
+                        return Err("empty entry, nothing added".to_string());
+                    }
+                    text
+                }
+            };
+            jf.journal.push(JournalEntry { written_at: now, entry: text });
+            write_journal(&dir, &jf, commit.then_some("journal: add entry"))?;
             println!("Entry added.");
         }
-        Commands::Entries => {
+        Commands::Entries(cmd) => {
             let mut jf = read_journal(&dir)?;
             jf.journal.sort_by(|a, b| b.written_at.cmp(&a.written_at));
-            if jf.journal.is_empty() {
-                println!("No entries yet.");
-            } else {
-                for e in &jf.journal {
-                    println!("- {}\n  {}\n", e.written_at, e.entry);
+            match cmd.format {
+                EntriesFormat::Plain => {
+                    if jf.journal.is_empty() {
+                        println!("No entries yet.");
+                    } else {
+                        for e in &jf.journal {
+                            println!("- {}\n  {}\n", e.written_at, e.entry);
+                        }
+                    }
+                }
+                EntriesFormat::Markdown => {
+                    for e in &jf.journal {
+                        println!("## {}\n\n{}\n", e.written_at, e.entry);
+                    }
+                }
+                EntriesFormat::Json => {
+                    let json = serde_json::to_string_pretty(&jf.journal).map_err(|e| e.to_string())?;
+                    println!("{}", json);
+                }
+            }
+        }
+        Commands::Draft(cmd) => {
+            let mut jf = read_journal(&dir)?;
+            match cmd.sub {
+                DraftSub::New { text } => {
+                    let id = allocate_draft_id(&jf.drafts)?;
+                    jf.drafts.push(Draft { id, text });
+                    write_journal(&dir, &jf, None)?;
+                    println!("Draft {} created.", id);
+                }
+                DraftSub::List => {
+                    if jf.drafts.is_empty() {
+                        println!("No drafts.");
+                    } else {
+                        for d in &jf.drafts {
+                            let first_line = d.text.lines().next().unwrap_or("");
+                            println!("{}: {}", d.id, first_line);
+                        }
+                    }
+                }
+                DraftSub::Edit { id } => {
+                    let draft = jf.drafts.iter_mut().find(|d| d.id == id)
+                        .ok_or_else(|| format!("No draft with id {}", id))?;
+                    let editor = resolve_editor(&cli.editor, config.editor.as_deref())?;
+                    draft.text = compose_entry_via_editor(&editor, &draft.text)?;
+                    write_journal(&dir, &jf, None)?;
+                    println!("Draft {} updated.", id);
+                }
+                DraftSub::Finish { id } => {
+                    let pos = jf.drafts.iter().position(|d| d.id == id)
+                        .ok_or_else(|| format!("No draft with id {}", id))?;
+                    let draft = jf.drafts.remove(pos);
+                    let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+                    jf.journal.push(JournalEntry { written_at: now, entry: draft.text });
+                    write_journal(&dir, &jf, commit.then_some(format!("journal: finish draft {}", id).as_str()))?;
+                    println!("Draft {} finished and added to journal.", id);
                 }
             }
         }
+        Commands::Status => {
+            print_git_status(&dir)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Reports whether the impl directory's enclosing git working tree is dirty: counts of
+/// new/modified/deleted/conflicted paths plus the current branch. Prints "not a git repo"
+/// rather than erroring, since `status` is a read-only convenience command.
+fn print_git_status(dir: &Path) -> Result<(), String> {
+    let repo = match git2::Repository::discover(dir) {
+        Ok(repo) => repo,
+        Err(_) => {
+            println!("not a git repo");
+            return Ok(());
+        }
+    };
+
+    let branch = repo
+        .head()
+        .ok()
+        .and_then(|h| h.shorthand().map(str::to_string))
+        .unwrap_or_else(|| "(detached HEAD)".to_string());
+
+    let statuses = repo.statuses(None).map_err(|e| e.to_string())?;
+    let (mut new, mut modified, mut deleted, mut conflicted) = (0, 0, 0, 0);
+    for entry in statuses.iter() {
+        let s = entry.status();
+        if s.is_conflicted() {
+            conflicted += 1;
+        } else if s.is_wt_new() || s.is_index_new() {
+            new += 1;
+        } else if s.is_wt_deleted() || s.is_index_deleted() {
+            deleted += 1;
+        } else if s.intersects(
+            git2::Status::WT_MODIFIED
+                | git2::Status::INDEX_MODIFIED
+                | git2::Status::WT_RENAMED
+                | git2::Status::INDEX_RENAMED
+                | git2::Status::WT_TYPECHANGE
+                | git2::Status::INDEX_TYPECHANGE,
+        ) {
+            modified += 1;
+        }
     }
 
+    println!("branch: {}", branch);
+    println!(
+        "dirty: {}",
+        if new + modified + deleted + conflicted > 0 { "yes" } else { "no" }
+    );
+    println!("new: {}", new);
+    println!("modified: {}", modified);
+    println!("deleted: {}", deleted);
+    println!("conflicted: {}", conflicted);
+
     Ok(())
 }
@@ -45,6 +45,23 @@ struct Args {
     /// with this path.
     #[arg(long)]
     base_path: Option<String>,
+
+    /// Absolute origin the site is served from, e.g. 'https://eddmann.github.io'
+    ///
+    /// Used to build fully-qualified `<link>`/`<id>` URLs in the generated Atom
+    /// feeds. Leave empty to emit feeds with root-relative URLs.
+    #[arg(long)]
+    site_url: Option<String>,
+
+    /// Ignore the `.santa-site-cache.json` and `.build-manifest.json` build caches and
+    /// regenerate every page from scratch
+    ///
+    /// By default, unchanged pages (same content hash as the previous build) are left
+    /// on disk untouched, and implementations whose inputs haven't changed skip
+    /// re-rendering entirely. Pass this to force a full regeneration, e.g. after editing
+    /// templates whose output hash the caches can't otherwise see has changed.
+    #[arg(long)]
+    force: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -87,6 +104,15 @@ struct ImplInfo {
     journal: JournalFile,
 }
 
+#[derive(Debug, Serialize, Clone)]
+struct SearchDoc {
+    id: String,
+    title: String,
+    url: String,
+    kind: String,
+    text: String,
+}
+
 fn repo_root() -> PathBuf {
     let cwd = std::env::current_dir().expect("cwd");
     if cwd.ends_with("tools") { cwd.parent().unwrap().to_path_buf() } else { cwd }
@@ -129,17 +155,133 @@ fn ensure_dir(p: &Path) -> Result<(), String> {
     fs::create_dir_all(p).map_err(|e| format!("Failed to create {}: {}", p.display(), e))
 }
 
-fn write_file(path: &Path, content: &str) -> Result<(), String> {
+/// Always writes `content` to `path`, bypassing the build cache. Used for the cache
+/// manifest itself, which must never be skipped by its own bookkeeping.
+fn write_file_raw(path: &Path, content: &str) -> Result<(), String> {
     if let Some(parent) = path.parent() { ensure_dir(parent)?; }
     let mut f = fs::File::create(path).map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
     f.write_all(content.as_bytes()).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
 }
 
+fn content_hash(content: &str) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Tracks which generated pages actually changed between runs, so `write_file` can skip
+/// rewriting output whose content hash hasn't moved. Persisted as `.santa-site-cache.json`
+/// (output path -> content hash) so the skip survives across invocations of `santa-site`.
+struct BuildCache {
+    manifest: BTreeMap<String, String>,
+    force: bool,
+    written: usize,
+    skipped: usize,
+}
+
+impl BuildCache {
+    const MANIFEST_FILE: &'static str = ".santa-site-cache.json";
+
+    fn load(out_dir: &Path, force: bool) -> Self {
+        let manifest = if force {
+            BTreeMap::new()
+        } else {
+            fs::read_to_string(out_dir.join(Self::MANIFEST_FILE))
+                .ok()
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default()
+        };
+        BuildCache { manifest, force, written: 0, skipped: 0 }
+    }
+
+    fn save(&self, out_dir: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.manifest).map_err(|e| e.to_string())?;
+        write_file_raw(&out_dir.join(Self::MANIFEST_FILE), &json)
+    }
+
+    fn summary(&self) -> String {
+        format!("{} page(s) written, {} unchanged and skipped", self.written, self.skipped)
+    }
+}
+
+/// Tracks a hash over each page's *inputs* (journal JSON, code tree, source file content),
+/// as opposed to `BuildCache` which hashes the rendered *output*. This lets `main` skip the
+/// relatively expensive journal-markdown-render and syntax-highlight pass for an
+/// implementation entirely, rather than only skipping the final file write. Persisted as
+/// `.build-manifest.json` in `out_dir`.
+struct InputManifest {
+    hashes: BTreeMap<String, String>,
+    force: bool,
+    rendered: usize,
+    reused: usize,
+}
+
+impl InputManifest {
+    const MANIFEST_FILE: &'static str = ".build-manifest.json";
+
+    fn load(out_dir: &Path, force: bool) -> Self {
+        let hashes = if force {
+            BTreeMap::new()
+        } else {
+            fs::read_to_string(out_dir.join(Self::MANIFEST_FILE))
+                .ok()
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default()
+        };
+        InputManifest { hashes, force, rendered: 0, reused: 0 }
+    }
+
+    fn save(&self, out_dir: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.hashes).map_err(|e| e.to_string())?;
+        write_file_raw(&out_dir.join(Self::MANIFEST_FILE), &json)
+    }
+
+    /// Returns true (and records `input`'s new hash under `key`) when it differs from the
+    /// previous build's, i.e. when the caller should actually (re-)render `key`.
+    fn changed(&mut self, key: &str, input: &str) -> bool {
+        let hash = content_hash(input);
+        let is_changed = self.force || self.hashes.get(key) != Some(&hash);
+        self.hashes.insert(key.to_string(), hash);
+        if is_changed { self.rendered += 1; } else { self.reused += 1; }
+        is_changed
+    }
+
+    fn summary(&self) -> String {
+        format!("{} impl(s) re-rendered, {} unchanged and reused", self.rendered, self.reused)
+    }
+}
+
+/// Writes `content` to `path`, skipping the actual filesystem write (but still recording
+/// the hash) when its content hash matches the previous build's, unless `--force` was passed.
+fn write_file(cache: &mut BuildCache, path: &Path, content: &str) -> Result<(), String> {
+    let key = path.to_string_lossy().to_string();
+    let hash = content_hash(content);
+    if !cache.force && cache.manifest.get(&key) == Some(&hash) {
+        cache.skipped += 1;
+        return Ok(());
+    }
+    write_file_raw(path, content)?;
+    cache.manifest.insert(key, hash);
+    cache.written += 1;
+    Ok(())
+}
+
 fn tailwind_head() -> String {
     // CDN Tailwind for simplicity; static hosting friendly
     // Includes a festive Google Font and basic base styles
     r#"<meta charset="utf-8" />
 <meta name="viewport" content="width=device-width, initial-scale=1" />
+<script>
+  // Applied before first paint so returning readers don't see a flash of the wrong theme.
+  (function() {
+    try {
+      var t = localStorage.getItem('site-theme') || 'dark';
+      document.documentElement.setAttribute('data-theme', t);
+    } catch (e) {}
+  })();
+</script>
 <script>
   window.tailwind = window.tailwind || {};
   tailwind.config = {
@@ -160,24 +302,58 @@ fn tailwind_head() -> String {
   }
 </script>
 <script src="https://cdn.tailwindcss.com?plugins=typography"></script>
-<link rel="stylesheet" href="https://cdnjs.cloudflare.com/ajax/libs/highlight.js/11.9.0/styles/github-dark.min.css">
+<link id="hljs-theme" rel="stylesheet" href="https://cdnjs.cloudflare.com/ajax/libs/highlight.js/11.9.0/styles/github-dark.min.css">
+<link rel="stylesheet" href="https://cdnjs.cloudflare.com/ajax/libs/KaTeX/0.16.9/katex.min.css">
+<script defer src="https://cdnjs.cloudflare.com/ajax/libs/KaTeX/0.16.9/katex.min.js"></script>
+<script defer src="https://cdnjs.cloudflare.com/ajax/libs/KaTeX/0.16.9/contrib/auto-render.min.js"></script>
 <script src="https://cdnjs.cloudflare.com/ajax/libs/highlight.js/11.9.0/highlight.min.js"></script>
+<script>
+  // elf-lang fences and the code browser are already highlighted server-side; keep hljs off them from the start.
+  if (window.hljs && window.hljs.configure) {
+    window.hljs.configure({ cssSelector: 'pre code:not(.language-santa):not(#code-content)' });
+  }
+</script>
 <link rel="preconnect" href="https://fonts.gstatic.com" crossorigin>
 <link href="https://fonts.googleapis.com/css2?family=Inter:wght@400;600;800&display=swap" rel="stylesheet">
 <style>
-  :root { --snow:#f0f9ff; --holly:#064e3b; --pine:#065f46; --candy:#f43f5e; --gold:#f59e0b; --header-h: 88px; }
+  :root, [data-theme="dark"] {
+    --snow:#f0f9ff; --holly:#064e3b; --pine:#065f46; --candy:#f43f5e; --gold:#f59e0b; --header-h: 88px;
+    --bg-from:#0B1220; --bg-via:#0f1a2d; --bg-to:#0B1220;
+    --fg:#ffffff; --muted:rgba(255,255,255,0.85); --border:rgba(255,255,255,0.1);
+    --paper:linear-gradient(180deg, rgba(255,255,255,0.9), rgba(255,255,255,0.95));
+    --paper-dark:linear-gradient(180deg, rgba(20,24,35,0.9), rgba(15,20,32,0.95));
+    --accent:#10b981; --accent-fg:#0b1220;
+    --hljs-theme: github-dark;
+  }
+  [data-theme="light"] {
+    --bg-from:#f8fafc; --bg-via:#eef2f7; --bg-to:#f8fafc;
+    --fg:#0f172a; --muted:rgba(15,23,42,0.75); --border:rgba(15,23,42,0.12);
+    --paper:linear-gradient(180deg, rgba(255,255,255,0.95), rgba(255,255,255,0.98));
+    --paper-dark:linear-gradient(180deg, rgba(241,245,249,0.92), rgba(226,232,240,0.96));
+    --accent:#059669; --accent-fg:#ffffff;
+    --hljs-theme: github;
+  }
+  [data-theme="ayu"] {
+    --bg-from:#0a0e14; --bg-via:#0d1016; --bg-to:#0a0e14;
+    --fg:#e6e1cf; --muted:rgba(230,225,207,0.8); --border:rgba(230,225,207,0.12);
+    --paper:linear-gradient(180deg, rgba(11,15,20,0.9), rgba(11,15,20,0.95));
+    --paper-dark:linear-gradient(180deg, rgba(11,15,20,0.92), rgba(11,15,20,0.97));
+    --accent:#ffb454; --accent-fg:#0a0e14;
+    --hljs-theme: tomorrow-night-bright;
+  }
   html { scroll-behavior: smooth; scroll-padding-top: var(--header-h); }
   .xmas-title { font-weight: 800; }
   .body-font { font-family: 'Inter', system-ui, -apple-system, Segoe UI, Roboto, Ubuntu, Cantarell, 'Helvetica Neue', Arial, 'Apple Color Emoji', 'Segoe UI Emoji'; }
-  .paper { background: linear-gradient(180deg, rgba(255,255,255,0.9), rgba(255,255,255,0.95)); box-shadow: 0 10px 30px rgba(0,0,0,0.08); }
-  .paper-dark { background: linear-gradient(180deg, rgba(20,24,35,0.9), rgba(15,20,32,0.95)); box-shadow: 0 10px 30px rgba(0,0,0,0.25); }
+  .site-body { background: linear-gradient(180deg, var(--bg-from), var(--bg-via), var(--bg-to)); color: var(--fg); }
+  .paper { background: var(--paper); box-shadow: 0 10px 30px rgba(0,0,0,0.08); }
+  .paper-dark { background: var(--paper-dark); box-shadow: 0 10px 30px rgba(0,0,0,0.25); }
   .journal-line { border-left: 3px solid var(--gold); }
   .code { font-family: ui-monospace, SFMono-Regular, Menlo, Monaco, Consolas, 'Liberation Mono', 'Courier New', monospace; }
-  .tab { border: 0; padding: 10px 16px; border-radius: 9999px; transition: background .15s ease, color .15s ease; color: rgba(255,255,255,0.85); cursor: pointer; font-weight: 600; }
-  .tab:hover { background: rgba(255,255,255,0.08); color: #fff; }
-  .tab-active { background: #ffffff; color: #0f172a; box-shadow: 0 1px 2px rgba(0,0,0,0.12); }
-  .theme-green .tab-active { background:#10b981; color:#0b1220; }
-  .journal-body { color: rgba(255,255,255,0.75); }
+  .tab { border: 0; padding: 10px 16px; border-radius: 9999px; transition: background .15s ease, color .15s ease; color: var(--muted); cursor: pointer; font-weight: 600; }
+  .tab:hover { background: rgba(128,128,128,0.15); color: var(--fg); }
+  .tab-active { background: var(--accent); color: var(--accent-fg); box-shadow: 0 1px 2px rgba(0,0,0,0.12); }
+  .journal-body { color: var(--muted); }
+  .math-display { display: block; margin: 1em 0; overflow-x: auto; }
   h1, h2, h3, h4, h5, h6 { scroll-margin-top: var(--header-h); }
   .snow, .snow2 {
     pointer-events: none; position: fixed; inset: 0; top: var(--header-h); z-index: 0; opacity: .22;
@@ -190,6 +366,18 @@ fn tailwind_head() -> String {
     animation: snow 25s linear infinite;
   }
   @media (min-width: 1024px) { .sticky-toc { position: sticky; top: calc(var(--header-h) + 1rem); } }
+  .diff-table { border-collapse: collapse; width: 100%; }
+  .diff-table td { padding: 0 0.5rem; white-space: pre; vertical-align: top; }
+  .diff-ln { color: var(--muted); text-align: right; user-select: none; width: 1%; }
+  .diff-equal { color: var(--fg); }
+  .diff-del { background: rgba(244,63,94,0.18); color: var(--fg); }
+  .diff-ins { background: rgba(16,185,129,0.18); color: var(--fg); }
+  .code-lines { font-variant-ligatures: none; }
+  .code-line { display: flex; }
+  .code-line-no { flex: 0 0 auto; width: 3em; padding-right: 0.75em; text-align: right; color: var(--muted); user-select: none; text-decoration: none; }
+  .code-line-no:hover { color: var(--fg); }
+  .code-line-text { flex: 1 1 auto; white-space: pre; }
+  .code-line:target, .code-line.line-highlight { background: rgba(250,204,21,0.15); }
   .snow2 { opacity: .18; animation-duration: 45s; background-size: 300px 300px; }
   @keyframes snow { from { background-position: 0 0, 0 0, 0 0, 0 0, 0 0; } to { background-position: 0 1000px, 0 800px, 0 600px, 0 400px, 0 200px; } }
 </style>"#
@@ -211,19 +399,60 @@ fn base_url(base: &str, path: &str) -> String {
     if base_norm.is_empty() { format!("/{}", p) } else { format!("{}/{}", base_norm, p) }
 }
 
-fn layout_with_base(title: &str, body: &str, base_path: &str) -> String {
+/// Builds a fully-qualified URL for feed output by prefixing `base_url`'s root-relative
+/// path with `site_url` (an absolute origin like 'https://eddmann.github.io'). Falls back
+/// to the root-relative path unchanged when `site_url` is empty.
+fn absolute_url(site_url: &str, base_path: &str, path: &str) -> String {
+    let origin = site_url.trim().trim_end_matches('/');
+    format!("{}{}", origin, base_url(base_path, path))
+}
+
+fn layout_with_base(title: &str, body: &str, base_path: &str, feed_links: &[(String, String)]) -> String {
+    let feed_links_html: String = feed_links
+        .iter()
+        .map(|(feed_title, href)| {
+            format!(
+                r#"<link rel="alternate" type="application/atom+xml" title="{}" href="{}">"#,
+                html_escape::encode_double_quoted_attribute(feed_title),
+                html_escape::encode_double_quoted_attribute(href)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n  ");
     let logo_src = base_url(base_path, "logo-light.png");
     let home_href = if normalize_base_path(base_path).is_empty() { "/".to_string() } else { format!("{}/", normalize_base_path(base_path)) };
     let lang_href = format!("{}/", base_url(base_path, "language"));
     let tasks_href = format!("{}/", base_url(base_path, "tasks"));
+    let search_index_href = base_url(base_path, "search-index.json");
+    let search_js_href = base_url(base_path, "search.js");
+    let theme_js_href = base_url(base_path, "theme.js");
+    let mermaid_tags = if body.contains("class=\"mermaid\"") {
+        r#"<script src="https://cdnjs.cloudflare.com/ajax/libs/mermaid/10.9.0/mermaid.min.js"></script>
+<script>
+  if (window.mermaid) {
+    mermaid.initialize({ startOnLoad: true, theme: 'dark' });
+  }
+</script>"#
+    } else {
+        ""
+    };
+    let syntax_css_tag = if body.contains(r#"id="code-content""#) {
+        let href = base_url(base_path, "syntax.css");
+        format!(r#"<link rel="stylesheet" href="{}">"#, href)
+    } else {
+        String::new()
+    };
     format!(
         r#"<!doctype html>
 <html lang="en" class="h-full">
 <head>
   <title>{}</title>
+  {feed_links}
   {}
+  {mermaid}
+  {syntax_css}
 </head>
-<body class="min-h-screen body-font bg-gradient-to-b from-[#0B1220] via-[#0f1a2d] to-[#0B1220] text-white relative theme-green">
+<body class="min-h-screen body-font site-body relative">
   <div class="snow"></div>
   <div class="snow2"></div>
   <header class="px-6 md:px-10 py-2 md:py-3 border-b border-white/10 backdrop-blur sticky top-0 bg-black/20 relative z-50">
@@ -232,6 +461,22 @@ fn layout_with_base(title: &str, body: &str, base_path: &str) -> String {
         <img src="{logo}" alt="elf-lang" class="h-12 md:h-14 w-auto"/>
       </a>
       <div class="ml-auto flex items-center gap-3">
+        <div class="relative">
+          <button id="search-open" class="flex items-center gap-2 px-3 py-1.5 rounded-full bg-white/10 hover:bg-white/20 text-white/70 border border-white/10 text-sm" aria-haspopup="dialog" aria-controls="search-panel">
+            <svg xmlns="http://www.w3.org/2000/svg" class="h-4 w-4" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-4.35-4.35M11 19a8 8 0 100-16 8 8 0 000 16z"/></svg>
+            <span class="hidden sm:inline">Search</span>
+          </button>
+        </div>
+        <div class="relative">
+          <button id="theme-open" class="flex items-center gap-2 px-3 py-1.5 rounded-full bg-white/10 hover:bg-white/20 text-white/70 border border-white/10 text-sm" aria-haspopup="menu" aria-controls="theme-menu" aria-label="Choose theme">
+            <svg xmlns="http://www.w3.org/2000/svg" class="h-4 w-4" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M12 3v1m0 16v1m9-9h-1M4 12H3m15.364 6.364l-.707-.707M6.343 6.343l-.707-.707m12.728 0l-.707.707M6.343 17.657l-.707.707M12 7a5 5 0 100 10 5 5 0 000-10z"/></svg>
+          </button>
+          <div id="theme-menu" class="hidden absolute right-0 mt-2 w-36 rounded-lg bg-black/90 border border-white/10 shadow-2xl overflow-hidden z-50" role="menu">
+            <button class="w-full text-left px-3 py-2 text-sm text-white/80 hover:bg-white/10" data-theme-choice="dark" role="menuitem">Dark</button>
+            <button class="w-full text-left px-3 py-2 text-sm text-white/80 hover:bg-white/10" data-theme-choice="light" role="menuitem">Light</button>
+            <button class="w-full text-left px-3 py-2 text-sm text-white/80 hover:bg-white/10" data-theme-choice="ayu" role="menuitem">Ayu</button>
+          </div>
+        </div>
         <nav class="hidden md:flex items-center gap-4">
           <a class="text-white/80 hover:text-white text-sm leading-none" href="{home}">Home</a>
           <a class="text-white/80 hover:text-white text-sm leading-none" href="{lang}">Language</a>
@@ -244,6 +489,15 @@ fn layout_with_base(title: &str, body: &str, base_path: &str) -> String {
       </div>
     </div>
   </header>
+  <div id="search-panel" class="hidden fixed inset-0 z-[60] bg-black/70 backdrop-blur-sm" role="dialog" aria-modal="true">
+    <div class="max-w-2xl mx-auto mt-24 px-4">
+      <div class="paper-dark rounded-xl border border-white/10 overflow-hidden shadow-2xl">
+        <input id="search-input" type="text" placeholder="Search implementations, journals and specs…" autocomplete="off"
+               class="w-full bg-black/30 text-white px-4 py-3 outline-none border-b border-white/10 placeholder-white/40"/>
+        <div id="search-results" class="max-h-[60vh] overflow-auto divide-y divide-white/5"></div>
+      </div>
+    </div>
+  </div>
   <div id="mobile-nav" class="md:hidden px-6 md:px-10 py-4 bg-gradient-to-b from-black/95 to-black/90 backdrop-blur-xl fixed left-0 right-0 z-40 shadow-2xl border-t border-white/10" style="display:none">
     <nav class="space-y-1">
       <a class="flex items-center py-3 px-4 rounded-lg text-white/80 hover:text-white hover:bg-white/10 text-sm font-medium transition-all duration-200 hover:translate-x-1 group" href="{home}">
@@ -302,41 +556,617 @@ fn layout_with_base(title: &str, body: &str, base_path: &str) -> String {
     }}
   }})();
   if (window.hljs && window.hljs.highlightAll) {{ window.hljs.highlightAll(); }}
-</script>"#, body),
+  if (window.katex) {{
+    document.querySelectorAll('.math-inline, .math-display').forEach(function(el) {{
+      try {{
+        window.katex.render(el.getAttribute('data-tex') || '', el, {{
+          displayMode: el.classList.contains('math-display'),
+          throwOnError: false
+        }});
+      }} catch (e) {{}}
+    }});
+  }}
+</script>
+<script src="{search_js}" data-search-index="{search_index}" defer></script>
+<script>
+  (function() {{
+    const openBtn = document.getElementById('search-open');
+    const panel = document.getElementById('search-panel');
+    const input = document.getElementById('search-input');
+    function open() {{
+      panel.classList.remove('hidden');
+      setTimeout(() => input && input.focus(), 0);
+    }}
+    function close() {{ panel.classList.add('hidden'); }}
+    openBtn?.addEventListener('click', open);
+    panel?.addEventListener('click', (e) => {{ if (e.target === panel) close(); }});
+    document.addEventListener('keydown', (e) => {{
+      if ((e.metaKey || e.ctrlKey) && e.key === 'k') {{ e.preventDefault(); open(); }}
+      if (e.key === 'Escape') close();
+    }});
+  }})();
+</script>
+<script src="{theme_js}" defer></script>
+<script>
+  (function() {{
+    const openBtn = document.getElementById('theme-open');
+    const menu = document.getElementById('theme-menu');
+    openBtn?.addEventListener('click', (e) => {{ e.stopPropagation(); menu.classList.toggle('hidden'); }});
+    document.addEventListener('click', () => menu?.classList.add('hidden'));
+    menu?.querySelectorAll('[data-theme-choice]').forEach((btn) => {{
+      btn.addEventListener('click', () => window.setSiteTheme(btn.getAttribute('data-theme-choice')));
+    }});
+  }})();
+</script>"#, body, search_js = search_js_href, search_index = search_index_href, theme_js = theme_js_href),
         logo = logo_src,
         home = home_href,
         lang = lang_href,
-        tasks = tasks_href
+        tasks = tasks_href,
+        mermaid = mermaid_tags,
+        feed_links = feed_links_html,
+        syntax_css = syntax_css_tag,
     )
 }
 
+fn global_feed_link(base_path: &str) -> (String, String) {
+    ("santa-lang Workshop Journal".to_string(), base_url(base_path, "atom.xml"))
+}
+
 fn layout(title: &str, body: &str, base_path: &str) -> String {
-    layout_with_base(title, body, base_path)
+    layout_with_base(title, body, base_path, &[global_feed_link(base_path)])
+}
+
+fn layout_impl(title: &str, body: &str, base_path: &str, impl_feed_title: &str, impl_feed_href: &str) -> String {
+    layout_with_base(
+        title,
+        body,
+        base_path,
+        &[global_feed_link(base_path), (impl_feed_title.to_string(), impl_feed_href.to_string())],
+    )
+}
+
+fn slugify(s: &str) -> String {
+    s.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else if c.is_whitespace() || c == '-' { '-' } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Replaces every `$...$` / `$$...$$` math span in `seg` with a
+/// `<span class="math-inline">` / `<div class="math-display">` placeholder carrying the raw
+/// TeX in a `data-tex` attribute. Outside math, `\$` becomes a literal `$`; a lone `$`
+/// followed by a digit, or one with no closing delimiter, is kept as-is.
+fn replace_math_in_segment(seg: &str) -> String {
+    let chars: Vec<char> = seg.chars().collect();
+    let mut out = String::new();
+    let mut i = 0usize;
+    while i < chars.len() {
+        if chars[i] == '\\' && i + 1 < chars.len() && chars[i + 1] == '$' {
+            out.push('$');
+            i += 2;
+            continue;
+        }
+        if chars[i] == '$' {
+            let is_display = i + 1 < chars.len() && chars[i + 1] == '$';
+            // A lone '$' followed by a digit reads as currency, not math.
+            if !is_display && i + 1 < chars.len() && chars[i + 1].is_ascii_digit() {
+                out.push('$');
+                i += 1;
+                continue;
+            }
+            let delim_len = if is_display { 2 } else { 1 };
+            let start = i + delim_len;
+            let mut j = start;
+            let mut end = None;
+            while j < chars.len() {
+                if chars[j] == '\\' && j + 1 < chars.len() && chars[j + 1] == '$' {
+                    j += 2;
+                    continue;
+                }
+                if chars[j] == '$' && (!is_display || (j + 1 < chars.len() && chars[j + 1] == '$')) {
+                    end = Some(j);
+                    break;
+                }
+                j += 1;
+            }
+            if let Some(end) = end {
+                let tex: String = chars[start..end].iter().collect::<String>().replace("\\$", "$");
+                let escaped = html_escape::encode_double_quoted_attribute(&tex);
+                if is_display {
+                    out.push_str(&format!(r#"<div class="math-display" data-tex="{}"></div>"#, escaped));
+                } else {
+                    out.push_str(&format!(r#"<span class="math-inline" data-tex="{}"></span>"#, escaped));
+                }
+                i = end + delim_len;
+                continue;
+            }
+        }
+        out.push(chars[i]);
+        i += 1;
+    }
+    out
+}
+
+/// Walks markdown source line by line, tracking ``` fences and splitting each non-fenced
+/// line on backticks (mirroring `escape_placeholders_outside_code`), so `$...$`/`$$...$$`
+/// math is only matched outside code and is replaced with placeholders pulldown-cmark
+/// passes through untouched.
+fn escape_math_outside_code(src: &str) -> String {
+    let mut out = String::new();
+    let mut in_fence = false;
+    for line in src.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+            out.push_str(line);
+            out.push('\n');
+            continue;
+        }
+        if in_fence {
+            out.push_str(line);
+            out.push('\n');
+            continue;
+        }
+        let mut result_line = String::new();
+        let mut parts = line.split('`').peekable();
+        let mut idx = 0usize;
+        while let Some(seg) = parts.next() {
+            if idx % 2 == 0 {
+                result_line.push_str(&replace_math_in_segment(seg));
+            } else {
+                result_line.push('`');
+                result_line.push_str(seg);
+                if parts.peek().is_some() { result_line.push('`'); }
+            }
+            idx += 1;
+        }
+        out.push_str(&result_line);
+        out.push('\n');
+    }
+    out
+}
+
+fn unescape_html_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// A small tokenizer-based syntax highlighter for elf-lang, in the spirit of rustdoc's
+/// `html/highlight.rs`: scan the source into a flat token stream, then wrap each token's
+/// exact byte span in a `<span class="hljs-*">`. Whitespace and punctuation we don't
+/// recognise are emitted unwrapped so the visible text round-trips exactly.
+mod elf_highlight {
+    const KEYWORDS: &[&str] = &[
+        "let", "mut", "if", "else", "match", "return", "true", "false", "nil",
+    ];
+
+    const BUILT_INS: &[&str] = &[
+        "puts", "print", "size", "push", "pop", "first", "rest", "last", "map", "filter",
+        "fold", "reduce", "each", "reverse", "sort", "sort_by", "zip", "range", "keys",
+        "values", "assoc", "dissoc", "get", "contains", "split", "join", "chars", "int",
+        "dec", "str", "type", "abs", "min", "max", "sum", "floor", "ceil", "round",
+    ];
+
+    // Longest-match-first so e.g. `|>` isn't split into `|` and `>`.
+    const MULTI_CHAR_OPERATORS: &[&str] = &[
+        "|>", "->", "==", "!=", "<=", ">=", "&&", "||", "..", "::",
+    ];
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum TokenKind {
+        Whitespace,
+        Comment,
+        String,
+        Number,
+        Keyword,
+        BuiltIn,
+        Ident,
+        Operator,
+        Other,
+    }
+
+    /// Splits `src` into `(kind, text)` tokens whose texts concatenate back to `src` exactly.
+    pub fn lex(src: &str) -> Vec<(TokenKind, &str)> {
+        let bytes = src.as_bytes();
+        let len = bytes.len();
+        let mut tokens = Vec::new();
+        let mut i = 0usize;
+
+        while i < len {
+            let start = i;
+            let c = src[i..].chars().next().unwrap();
+
+            if c.is_whitespace() {
+                while i < len && src[i..].chars().next().is_some_and(|c| c.is_whitespace()) {
+                    i += src[i..].chars().next().unwrap().len_utf8();
+                }
+                tokens.push((TokenKind::Whitespace, &src[start..i]));
+                continue;
+            }
+
+            if c == '/' && bytes.get(i + 1) == Some(&b'/') {
+                while i < len && bytes[i] != b'\n' {
+                    i += 1;
+                }
+                tokens.push((TokenKind::Comment, &src[start..i]));
+                continue;
+            }
+
+            if c == '/' && bytes.get(i + 1) == Some(&b'*') {
+                i += 2;
+                while i < len && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i = (i + 2).min(len);
+                tokens.push((TokenKind::Comment, &src[start..i]));
+                continue;
+            }
+
+            if c == '"' || c == '\'' {
+                let quote = c;
+                i += 1;
+                while i < len {
+                    if bytes[i] == b'\\' && i + 1 < len {
+                        i += 1 + src[i + 1..].chars().next().unwrap().len_utf8();
+                        continue;
+                    }
+                    if src[i..].starts_with(quote) {
+                        i += 1;
+                        break;
+                    }
+                    i += src[i..].chars().next().unwrap().len_utf8();
+                }
+                tokens.push((TokenKind::String, &src[start..i]));
+                continue;
+            }
+
+            if c.is_ascii_digit() {
+                while i < len && (bytes[i].is_ascii_digit() || bytes[i] == b'_') {
+                    i += 1;
+                }
+                if bytes.get(i) == Some(&b'.') && bytes.get(i + 1).is_some_and(u8::is_ascii_digit) {
+                    i += 1;
+                    while i < len && (bytes[i].is_ascii_digit() || bytes[i] == b'_') {
+                        i += 1;
+                    }
+                }
+                tokens.push((TokenKind::Number, &src[start..i]));
+                continue;
+            }
+
+            if c.is_alphabetic() || c == '_' {
+                while i < len && src[i..].chars().next().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                    i += src[i..].chars().next().unwrap().len_utf8();
+                }
+                let word = &src[start..i];
+                let kind = if KEYWORDS.contains(&word) {
+                    TokenKind::Keyword
+                } else if BUILT_INS.contains(&word) {
+                    TokenKind::BuiltIn
+                } else {
+                    TokenKind::Ident
+                };
+                tokens.push((kind, word));
+                continue;
+            }
+
+            if let Some(op) = MULTI_CHAR_OPERATORS.iter().find(|op| src[start..].starts_with(*op)) {
+                i += op.len();
+                tokens.push((TokenKind::Operator, &src[start..i]));
+                continue;
+            }
+
+            const OPERATOR_CHARS: &str = "+-*/%=<>!&|^~.,:;(){}[]?@";
+            if OPERATOR_CHARS.contains(c) {
+                i += c.len_utf8();
+                tokens.push((TokenKind::Operator, &src[start..i]));
+                continue;
+            }
+
+            i += c.len_utf8();
+            tokens.push((TokenKind::Other, &src[start..i]));
+        }
+
+        tokens
+    }
+
+    /// Renders `src` as HTML, wrapping each recognised token in a `<span class="hljs-*">`
+    /// (matching highlight.js's own class names so the page's existing hljs theme CSS applies).
+    pub fn highlight_to_html(src: &str) -> String {
+        let mut out = String::new();
+        for (kind, text) in lex(src) {
+            let escaped = html_escape::encode_text(text);
+            match kind {
+                TokenKind::Whitespace | TokenKind::Other => out.push_str(&escaped),
+                TokenKind::Comment => out.push_str(&format!(r#"<span class="hljs-comment">{}</span>"#, escaped)),
+                TokenKind::String => out.push_str(&format!(r#"<span class="hljs-string">{}</span>"#, escaped)),
+                TokenKind::Number => out.push_str(&format!(r#"<span class="hljs-number">{}</span>"#, escaped)),
+                TokenKind::Keyword => out.push_str(&format!(r#"<span class="hljs-keyword">{}</span>"#, escaped)),
+                TokenKind::BuiltIn => out.push_str(&format!(r#"<span class="hljs-built_in">{}</span>"#, escaped)),
+                TokenKind::Operator => out.push_str(&format!(r#"<span class="hljs-operator">{}</span>"#, escaped)),
+                TokenKind::Ident => out.push_str(&escaped),
+            }
+        }
+        out
+    }
+}
+
+/// Build-time syntax highlighting for the per-implementation code browser, in the same
+/// spirit as rustdoc's `html/highlight.rs`: tokenize each file once at generation time and
+/// emit pre-classed spans, rather than shipping raw source for `hljs.highlightElement` to
+/// tokenize in the browser.
+mod code_highlight {
+    use syntect::html::{css_for_theme_with_class_style, ClassStyle, ClassedHTMLGenerator};
+    use syntect::highlighting::ThemeSet;
+    use syntect::parsing::SyntaxSet;
+    use syntect::util::LinesWithEndings;
+
+    const CLASS_PREFIX: &str = "tok-";
+    const THEME_NAME: &str = "InspiredGitHub";
+    /// Files larger than this fall back to plain escaped text rather than being tokenized.
+    const MAX_HIGHLIGHT_BYTES: usize = 200_000;
+
+    pub struct Highlighter {
+        syntax_set: SyntaxSet,
+    }
+
+    impl Highlighter {
+        pub fn new() -> Self {
+            Highlighter { syntax_set: SyntaxSet::load_defaults_newlines() }
+        }
+
+        /// Renders `content` (named `filename`, used only for its extension) as HTML with
+        /// each token wrapped in a `class="tok-*"` span. `.santa` files get the real
+        /// elf-lang highlighter instead of a fallback, since one already exists; files with
+        /// an unrecognised extension, or ones over `MAX_HIGHLIGHT_BYTES`, fall back to
+        /// plain escaped text.
+        pub fn highlight(&self, filename: &str, content: &str) -> String {
+            if content.len() > MAX_HIGHLIGHT_BYTES {
+                return html_escape::encode_text(content).to_string();
+            }
+            if filename.ends_with(".santa") {
+                return super::elf_highlight::highlight_to_html(content);
+            }
+            let ext = std::path::Path::new(filename)
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("");
+            let Some(syntax) = self.syntax_set.find_syntax_by_extension(ext) else {
+                return html_escape::encode_text(content).to_string();
+            };
+            let mut generator = ClassedHTMLGenerator::new_with_class_style(
+                syntax,
+                &self.syntax_set,
+                ClassStyle::SpacedPrefixed { prefix: CLASS_PREFIX },
+            );
+            for line in LinesWithEndings::from(content) {
+                if generator.parse_html_for_line_which_includes_newline(line).is_err() {
+                    return html_escape::encode_text(content).to_string();
+                }
+            }
+            generator.finalize()
+        }
+    }
+
+    /// CSS for the theme used above, keyed to the same `tok-` class prefix, to ship instead
+    /// of the hljs theme bundle for the code browser.
+    pub fn theme_css() -> String {
+        let theme_set = ThemeSet::load_defaults();
+        let theme = &theme_set.themes[THEME_NAME];
+        css_for_theme_with_class_style(theme, ClassStyle::SpacedPrefixed { prefix: CLASS_PREFIX })
+            .unwrap_or_default()
+    }
+}
+
+/// Myers' O(ND) diff algorithm over lines, for the side-by-side implementation comparison
+/// pages. Follows the classic formulation: explore diagonals `k = x - y`, where `v[k]` holds
+/// the furthest-reaching `x` on diagonal `k` for edit distance `d`, advance greedily along
+/// matching lines ("snakes"), and stop once `x >= a.len() && y >= b.len()`. The per-`d`
+/// frontier snapshots are then walked backwards to classify each line as unchanged, deleted
+/// (only in `a`) or inserted (only in `b`).
+mod myers_diff {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum DiffOp<'a> {
+        Equal(&'a str),
+        Delete(&'a str),
+        Insert(&'a str),
+    }
+
+    pub fn diff_lines<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<DiffOp<'a>> {
+        let n = a.len() as isize;
+        let m = b.len() as isize;
+        let max = n + m;
+        if max == 0 {
+            return Vec::new();
+        }
+        let offset = max as usize;
+        let mut v = vec![0isize; 2 * max as usize + 1];
+        let mut trace: Vec<Vec<isize>> = Vec::new();
+
+        let mut final_d = 0isize;
+        'outer: for d in 0..=max {
+            trace.push(v.clone());
+            let mut k = -d;
+            while k <= d {
+                let idx = (k + offset as isize) as usize;
+                let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                    v[idx + 1]
+                } else {
+                    v[idx - 1] + 1
+                };
+                let mut y = x - k;
+                while x < n && y < m && a[x as usize] == b[y as usize] {
+                    x += 1;
+                    y += 1;
+                }
+                v[idx] = x;
+                if x >= n && y >= m {
+                    final_d = d;
+                    break 'outer;
+                }
+                k += 2;
+            }
+        }
+
+        // Backtrack through the recorded frontiers to recover the edit script, then
+        // reverse it since we walk from (n, m) back to (0, 0).
+        let mut ops = Vec::new();
+        let (mut x, mut y) = (n, m);
+        for d in (0..=final_d).rev() {
+            let v = &trace[d as usize];
+            let k = x - y;
+            let idx = (k + offset as isize) as usize;
+            let prev_k = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                k + 1
+            } else {
+                k - 1
+            };
+            let prev_idx = (prev_k + offset as isize) as usize;
+            let prev_x = v[prev_idx];
+            let prev_y = prev_x - prev_k;
+
+            while x > prev_x && y > prev_y {
+                ops.push(DiffOp::Equal(a[(x - 1) as usize]));
+                x -= 1;
+                y -= 1;
+            }
+            if d > 0 {
+                if x == prev_x {
+                    ops.push(DiffOp::Insert(b[(y - 1) as usize]));
+                } else {
+                    ops.push(DiffOp::Delete(a[(x - 1) as usize]));
+                }
+            }
+            x = prev_x;
+            y = prev_y;
+        }
+        ops.reverse();
+        ops
+    }
+}
+
+/// Rewrites ` ```santa ` fenced blocks (already rendered by pulldown-cmark as
+/// `<pre><code class="language-santa">...</code></pre>`) into highlighted HTML, replacing
+/// the flat `.replace("class=\"language-santa\"", "class=\"language-plaintext\"")` downgrade
+/// this used to do with real elf-lang token spans.
+fn rewrite_santa_fences(html: &str) -> String {
+    let re = regex::Regex::new(r#"(?s)<pre><code class="language-santa">(.*?)</code></pre>"#).unwrap();
+    re.replace_all(html, |caps: &regex::Captures| {
+        let raw = unescape_html_entities(&caps[1]);
+        format!(
+            r#"<pre><code class="language-santa hljs">{}</code></pre>"#,
+            elf_highlight::highlight_to_html(&raw)
+        )
+    })
+    .to_string()
+}
+
+/// Rewrites ` ```mermaid ` fenced blocks (already rendered by pulldown-cmark as
+/// `<pre><code class="language-mermaid">...</code></pre>`) into `<pre class="mermaid">raw</pre>`
+/// so mermaid.js picks them up instead of highlight.js.
+fn rewrite_mermaid_fences(html: &str) -> String {
+    let re = regex::Regex::new(r#"(?s)<pre><code class="language-mermaid">(.*?)</code></pre>"#).unwrap();
+    re.replace_all(html, |caps: &regex::Captures| {
+        format!("<pre class=\"mermaid\">{}</pre>", unescape_html_entities(&caps[1]))
+    }).to_string()
 }
 
-fn layout_impl(title: &str, body: &str, base_path: &str) -> String {
-    layout_with_base(title, body, base_path)
+/// TF-IDF over each implementation's journal prose and source tree, backing the "Related
+/// implementations" strip on `render_impl`. Kept dependency-light and deterministic: no
+/// neural embeddings, just term frequency, document frequency and cosine similarity.
+mod tfidf {
+    use std::collections::BTreeMap;
+
+    /// Lowercase, split on non-alphanumerics, drop tokens shorter than 3 chars — tighter
+    /// than the free-text `tokenize` used by `build_impl_index`, so short common tokens
+    /// (IDs, loop vars, "fn", "if") don't dominate the similarity signal.
+    fn tokenize(s: &str) -> Vec<String> {
+        s.to_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|t| t.len() >= 3)
+            .map(|t| t.to_string())
+            .collect()
+    }
+
+    /// For each document, the indices and cosine-similarity scores of its top 3 most
+    /// similar other documents, skipping itself and near-zero scores.
+    pub fn related(docs: &[String]) -> Vec<Vec<(usize, f64)>> {
+        let n = docs.len();
+        let term_counts: Vec<BTreeMap<String, usize>> = docs
+            .iter()
+            .map(|doc| {
+                let mut counts = BTreeMap::new();
+                for token in tokenize(doc) {
+                    *counts.entry(token).or_insert(0usize) += 1;
+                }
+                counts
+            })
+            .collect();
+
+        let mut doc_freq: BTreeMap<&str, usize> = BTreeMap::new();
+        for counts in &term_counts {
+            for term in counts.keys() {
+                *doc_freq.entry(term.as_str()).or_insert(0) += 1;
+            }
+        }
+
+        // tf * ln(N / df) per term, then L2-normalized so cosine similarity is a plain dot product.
+        let vectors: Vec<BTreeMap<String, f64>> = term_counts
+            .iter()
+            .map(|counts| {
+                let mut weights: BTreeMap<String, f64> = counts
+                    .iter()
+                    .map(|(term, tf)| {
+                        let df = doc_freq[term.as_str()] as f64;
+                        (term.clone(), *tf as f64 * (n as f64 / df).ln())
+                    })
+                    .collect();
+                let norm = weights.values().map(|w| w * w).sum::<f64>().sqrt();
+                if norm > 0.0 {
+                    for w in weights.values_mut() {
+                        *w /= norm;
+                    }
+                }
+                weights
+            })
+            .collect();
+
+        (0..n)
+            .map(|i| {
+                let mut scores: Vec<(usize, f64)> = (0..n)
+                    .filter(|&j| j != i)
+                    .map(|j| (j, cosine(&vectors[i], &vectors[j])))
+                    .filter(|&(_, score)| score > 1e-6)
+                    .collect();
+                scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+                scores.truncate(3);
+                scores
+            })
+            .collect()
+    }
+
+    fn cosine(a: &BTreeMap<String, f64>, b: &BTreeMap<String, f64>) -> f64 {
+        let (smaller, larger) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+        smaller.iter().filter_map(|(term, w)| larger.get(term).map(|ow| w * ow)).sum()
+    }
 }
 
 fn render_language_page(root: &Path, base_path: &str) -> String {
     let lang_path = root.join("specs").join("LANG.md");
     let (html, toc) = if let Ok(md) = fs::read_to_string(&lang_path) {
+        let md = escape_math_outside_code(&md);
         let mut opts = MdOptions::empty();
         opts.insert(MdOptions::ENABLE_TABLES);
         opts.insert(MdOptions::ENABLE_FOOTNOTES);
         let parser = MdParser::new_ext(&md, opts);
         let mut out = String::new();
         md_html::push_html(&mut out, parser);
-        // Helper to slugify titles
-        fn slugify(s: &str) -> String {
-            s.to_lowercase()
-                .chars()
-                .map(|c| if c.is_alphanumeric() { c } else if c.is_whitespace() || c == '-' { '-' } else { ' ' })
-                .collect::<String>()
-                .split_whitespace()
-                .collect::<Vec<_>>()
-                .join("-")
-        }
         // Build TOC (exclude H1)
         let mut toc_items = Vec::new();
         for line in md.lines() {
@@ -372,8 +1202,9 @@ fn render_language_page(root: &Path, base_path: &str) -> String {
             last_end = m.end();
         }
         html_with_ids.push_str(&out[last_end..]);
-        // Normalize unknown language fences for highlight.js
-        html_with_ids = html_with_ids.replace("class=\"language-santa\"", "class=\"language-plaintext\"");
+        // Highlight elf-lang fences server-side instead of downgrading them to plaintext
+        html_with_ids = rewrite_santa_fences(&html_with_ids);
+        html_with_ids = rewrite_mermaid_fences(&html_with_ids);
 
         let mut toc_html = String::new();
         for (level, title, id) in toc_items {
@@ -448,22 +1279,13 @@ fn render_tasks_page(root: &Path, base_path: &str) -> String {
         out
     }
     let (html, toc) = if let Ok(md) = fs::read_to_string(&tasks_path) {
-        let processed_md = escape_placeholders_outside_code(&md);
+        let processed_md = escape_math_outside_code(&escape_placeholders_outside_code(&md));
         let mut opts = MdOptions::empty();
         opts.insert(MdOptions::ENABLE_TABLES);
         opts.insert(MdOptions::ENABLE_FOOTNOTES);
         let parser = MdParser::new_ext(&processed_md, opts);
         let mut out = String::new();
         md_html::push_html(&mut out, parser);
-        fn slugify(s: &str) -> String {
-            s.to_lowercase()
-                .chars()
-                .map(|c| if c.is_alphanumeric() { c } else if c.is_whitespace() || c == '-' { '-' } else { ' ' })
-                .collect::<String>()
-                .split_whitespace()
-                .collect::<Vec<_>>()
-                .join("-")
-        }
         let mut toc_items = Vec::new();
         for line in processed_md.lines() {
             let trimmed = line.trim();
@@ -497,8 +1319,9 @@ fn render_tasks_page(root: &Path, base_path: &str) -> String {
             last_end = m.end();
         }
         html_with_ids.push_str(&out[last_end..]);
-        // Normalize unknown language fences for highlight.js
-        html_with_ids = html_with_ids.replace("class=\"language-santa\"", "class=\"language-plaintext\"");
+        // Highlight elf-lang fences server-side instead of downgrading them to plaintext
+        html_with_ids = rewrite_santa_fences(&html_with_ids);
+        html_with_ids = rewrite_mermaid_fences(&html_with_ids);
 
         let mut toc_html = String::new();
         for (level, title, id) in toc_items {
@@ -561,7 +1384,7 @@ fn render_index(impls: &[ImplInfo], intro_html: &str, base_path: &str) -> String
         if !harness.trim().is_empty() { harnesses.insert(harness.clone()); }
         if !model.trim().is_empty() { models.insert(model.clone()); }
         cards.push_str(&format!(
-            r#"<a class="impl-card group overflow-hidden rounded-xl paper-dark bg-white/5 border border-white/10 hover:shadow-glow transition" href="impl/{dir}/index.html" data-lang="{data_lang}" data-harness="{data_harness}" data-model="{data_model}">
+            r#"<a class="impl-card group overflow-hidden rounded-xl paper-dark bg-white/5 border border-white/10 hover:shadow-glow transition" href="impl/{dir}/index.html" data-dir="{data_dir}" data-lang="{data_lang}" data-harness="{data_harness}" data-model="{data_model}">
   <div class="aspect-square overflow-hidden bg-black/30">
     <img class="w-full h-full object-cover object-center group-hover:scale-105 transition" src="{img}" alt="Elf {author}">
   </div>
@@ -581,6 +1404,7 @@ fn render_index(impls: &[ImplInfo], intro_html: &str, base_path: &str) -> String
             lang = html_escape::encode_text(&lang),
             harness = html_escape::encode_text(&harness),
             model = html_escape::encode_text(&model),
+            data_dir = html_escape::encode_double_quoted_attribute(&ii.dir_name),
             data_lang = html_escape::encode_double_quoted_attribute(&lang),
             data_harness = html_escape::encode_double_quoted_attribute(&harness),
             data_model = html_escape::encode_double_quoted_attribute(&model),
@@ -608,6 +1432,13 @@ fn render_index(impls: &[ImplInfo], intro_html: &str, base_path: &str) -> String
     </div>
   </div>
   <div id="filters-panel" class="mt-2 hidden md:block">
+    <div class="mb-2">
+      <label class="flex items-center gap-2 px-3 py-2 rounded-full bg-black/20 border border-white/10">
+        <span class="text-xs text-white/60">Search</span>
+        <input id="filter-text" type="text" placeholder="author, journal prose, filenames…" autocomplete="off"
+               class="bg-transparent text-sm text-white/90 w-full outline-none placeholder-white/40"/>
+      </label>
+    </div>
     <div class="grid grid-cols-1 md:grid-cols-3 gap-2">
       <label class="flex items-center gap-2 px-3 py-2 rounded-full bg-black/20 border border-white/10">
         <span class="text-xs text-white/60">Language</span>
@@ -635,6 +1466,30 @@ fn render_index(impls: &[ImplInfo], intro_html: &str, base_path: &str) -> String
         model_opts = model_opts,
     );
 
+    let mut compare_opts = String::new();
+    for ii in impls {
+        compare_opts.push_str(&format!(
+            "<option value=\"{dir}\">{dir}</option>",
+            dir = html_escape::encode_double_quoted_attribute(&ii.dir_name)
+        ));
+    }
+    let compare = format!(
+        r#"<div class="mt-4 w-full rounded-xl bg-white/5 backdrop-blur border border-white/15 p-3">
+  <div class="flex flex-col md:flex-row items-center gap-2">
+    <div class="text-white/80 font-semibold mr-2">Compare</div>
+    <select id="compare-a" class="bg-black/20 border border-white/10 rounded-full text-sm text-white/90 px-3 py-2 outline-none">
+      {compare_opts}
+    </select>
+    <span class="text-white/50 text-sm">vs</span>
+    <select id="compare-b" class="bg-black/20 border border-white/10 rounded-full text-sm text-white/90 px-3 py-2 outline-none">
+      {compare_opts}
+    </select>
+    <button id="compare-go" class="px-3 py-1.5 rounded-full bg-white/10 hover:bg-white/20 text-white/80 border border-white/10 text-sm">Compare implementations</button>
+  </div>
+</div>"#,
+        compare_opts = compare_opts
+    );
+
     let body = format!(
         r#"<section class="paper-dark rounded-xl p-6 border border-white/10">
   <div class="prose prose-invert max-w-none">{}</div>
@@ -642,6 +1497,7 @@ fn render_index(impls: &[ImplInfo], intro_html: &str, base_path: &str) -> String
 
 <h2 class="mt-10 text-3xl font-semibold text-white/90">🎄 Showcase</h2>
 {filters}
+{compare}
 <section id="showcase-grid" class="mt-4 grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-6">
   {}
 </section>
@@ -651,28 +1507,59 @@ fn render_index(impls: &[ImplInfo], intro_html: &str, base_path: &str) -> String
     const selLang = document.getElementById('filter-lang');
     const selHarness = document.getElementById('filter-harness');
     const selModel = document.getElementById('filter-model');
+    const textInput = document.getElementById('filter-text');
     const grid = document.getElementById('showcase-grid');
     const cards = Array.from(grid ? grid.querySelectorAll('.impl-card') : []);
     const empty = document.getElementById('empty-state');
     function val(x) {{ return (x && x.value ? x.value : '').toLowerCase(); }}
+
+    // Lazily fetched inverted index (token -> impl indices) backing the text search box.
+    let implIndex = null;
+    function ensureIndexLoaded() {{
+      if (implIndex) return Promise.resolve(implIndex);
+      return fetch('impl-index.json').then(r => r.json()).then(data => {{ implIndex = data; return data; }});
+    }}
+    function tokenize(s) {{ return (s.toLowerCase().match(/[a-z0-9]+/g) || []); }}
+    function dirsMatchingText(query) {{
+      if (!implIndex) return null;
+      const terms = tokenize(query);
+      if (terms.length === 0) return null;
+      let matching = null;
+      for (const term of terms) {{
+        const hit = new Set();
+        for (const token of Object.keys(implIndex.tokens)) {{
+          if (token.startsWith(term)) {{
+            for (const idx of implIndex.tokens[token]) hit.add(implIndex.impls[idx].dir_name);
+          }}
+        }}
+        matching = matching === null ? hit : new Set([...matching].filter(d => hit.has(d)));
+      }}
+      return matching;
+    }}
+
     function apply() {{
       const lv = val(selLang), hv = val(selHarness), mv = val(selModel);
+      const tv = (textInput && textInput.value ? textInput.value.trim() : '');
+      const matchingDirs = tv ? dirsMatchingText(tv) : null;
       let visible = 0;
       cards.forEach(card => {{
         const ok = (!lv || (card.getAttribute('data-lang')||'').toLowerCase() === lv)
           && (!hv || (card.getAttribute('data-harness')||'').toLowerCase() === hv)
-          && (!mv || (card.getAttribute('data-model')||'').toLowerCase() === mv);
+          && (!mv || (card.getAttribute('data-model')||'').toLowerCase() === mv)
+          && (matchingDirs === null || matchingDirs.has(card.getAttribute('data-dir')||''));
         card.style.display = ok ? '' : 'none';
         if (ok) visible++;
       }});
       if (empty) empty.style.display = visible ? 'none' : '';
     }}
     [selLang, selHarness, selModel].forEach(s => s && s.addEventListener('change', apply));
+    textInput?.addEventListener('input', () => {{ ensureIndexLoaded().then(apply); }});
     const clearBtn = document.getElementById('filters-clear');
     clearBtn?.addEventListener('click', () => {{
       if (selLang) selLang.value = '';
       if (selHarness) selHarness.value = '';
       if (selModel) selModel.value = '';
+      if (textInput) textInput.value = '';
       apply();
     }});
     const toggleBtn = document.getElementById('filters-toggle');
@@ -686,15 +1573,98 @@ fn render_index(impls: &[ImplInfo], intro_html: &str, base_path: &str) -> String
     }});
     apply();
   }})();
+  (function() {{
+    const selA = document.getElementById('compare-a');
+    const selB = document.getElementById('compare-b');
+    const goBtn = document.getElementById('compare-go');
+    goBtn?.addEventListener('click', () => {{
+      const a = selA && selA.value, b = selB && selB.value;
+      if (!a || !b || a === b) return;
+      const slug = a <= b ? `${{a}}-vs-${{b}}` : `${{b}}-vs-${{a}}`;
+      window.location.href = `compare/${{slug}}/`;
+    }});
+  }})();
 </script>"#,
         intro_html,
         cards,
-        filters = filters
+        filters = filters,
+        compare = compare
     );
     layout("santa-lang Workshop", &body, base_path)
 }
 
-fn render_impl(imp: &ImplInfo, tree: &BTreeMap<String, String>, base_path: &str) -> String {
+/// Splits syntax-highlighted HTML (one `<span class="tok-*">`-per-token blob, as produced by
+/// `code_highlight::Highlighter`/`elf_highlight`) back into one HTML string per source line,
+/// closing any spans left open at a line break and reopening them at the start of the next
+/// line so each line's markup stays independently well-formed.
+fn split_highlighted_lines(html: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut stack: Vec<String> = Vec::new();
+    let mut i = 0usize;
+    let len = html.len();
+    while i < len {
+        if html.as_bytes()[i] == b'\n' {
+            for _ in 0..stack.len() {
+                current.push_str("</span>");
+            }
+            lines.push(std::mem::take(&mut current));
+            for tag in &stack {
+                current.push_str(tag);
+            }
+            i += 1;
+            continue;
+        }
+        if html[i..].starts_with("<span") {
+            if let Some(rel_end) = html[i..].find('>') {
+                let tag = html[i..=i + rel_end].to_string();
+                current.push_str(&tag);
+                stack.push(tag);
+                i += rel_end + 1;
+                continue;
+            }
+        }
+        if html[i..].starts_with("</span>") {
+            current.push_str("</span>");
+            stack.pop();
+            i += "</span>".len();
+            continue;
+        }
+        let ch = html[i..].chars().next().unwrap();
+        current.push(ch);
+        i += ch.len_utf8();
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Wraps each line of `html` (already syntax-highlighted) in a `.code-line` row with a
+/// line-number gutter anchor `#{rel}:L{n}`, so a shared URL can deep-link to and highlight a
+/// specific line, or `#{rel}:L10-L20` for a range — see the `hashchange` handler wired up in
+/// the code browser's `<script>` in [`render_impl`]. Matches rustdoc's source-line anchors.
+fn gutter_wrap(rel: &str, html: &str) -> String {
+    let mut out = String::new();
+    for (i, line) in split_highlighted_lines(html).into_iter().enumerate() {
+        let n = i + 1;
+        out.push_str(&format!(
+            r#"<div class="code-line" id="L{n}"><a class="code-line-no" href="#{href}" data-line-link="{n}">{n}</a><span class="code-line-text">{line}</span></div>"#,
+            n = n,
+            href = html_escape::encode_double_quoted_attribute(&format!("{}:L{}", rel, n)),
+            line = line
+        ));
+    }
+    out
+}
+
+fn render_impl(
+    imp: &ImplInfo,
+    tree: &BTreeMap<String, String>,
+    base_path: &str,
+    highlighter: &code_highlight::Highlighter,
+    related: &[&ImplInfo],
+) -> String {
     let author = if imp.journal.author.trim().is_empty() { "Unknown Elf" } else { &imp.journal.author };
     let mut entries = imp.journal.journal.clone();
     entries.sort_by(|a, b| b.written_at.cmp(&a.written_at));
@@ -711,6 +1681,7 @@ fn render_impl(imp: &ImplInfo, tree: &BTreeMap<String, String>, base_path: &str)
             let mdp = MdParser::new_ext(&e.entry, opts);
             let mut rendered = String::new();
             md_html::push_html(&mut rendered, mdp);
+            rendered = rewrite_mermaid_fences(&rendered);
             journal_html.push_str(&format!(
                 r#"<article class="paper-dark rounded-lg p-5 border border-white/10 mb-4">
   <div class="text-xs text-white/50">{date}</div>
@@ -740,6 +1711,15 @@ fn render_impl(imp: &ImplInfo, tree: &BTreeMap<String, String>, base_path: &str)
 
     let initial_file = first_file.map(|(r, c)| (r.to_string(), c.clone())).unwrap_or((String::new(), String::new()));
 
+    // Every file is tokenized and classed into HTML at build time, then wrapped with a
+    // line-number gutter ([`gutter_wrap`]) so the browser only ever swaps in pre-rendered
+    // markup — no client-side re-tokenizing or highlight.js dependency.
+    let highlighted: BTreeMap<&str, String> = tree
+        .iter()
+        .map(|(rel, content)| (rel.as_str(), gutter_wrap(rel, &highlighter.highlight(rel, content))))
+        .collect();
+    let initial_html = highlighted.get(initial_file.0.as_str()).cloned().unwrap_or_default();
+
     let code_browser = format!(
         r#"<div class="grid grid-cols-1 lg:grid-cols-4 gap-4">
   <aside class="paper-dark rounded-lg p-3 border border-white/10 lg:col-span-1 max-h-[60vh] overflow-auto">
@@ -750,51 +1730,90 @@ fn render_impl(imp: &ImplInfo, tree: &BTreeMap<String, String>, base_path: &str)
       <span id="code-filename">{fname}</span>
       <button id="copy-code" class="px-2 py-1 rounded bg-white/10 hover:bg-white/20 text-white/80">Copy</button>
     </div>
-    <pre class="code text-sm p-4 overflow-auto max-h-[60vh] bg-black/30"><code id="code-content" class="hljs">{content}</code></pre>
+    <div class="code text-sm p-4 overflow-auto max-h-[60vh] bg-black/30"><div id="code-content" class="code-lines">{content}</div></div>
   </section>
 </div>
 <script>
   const files = {files_json};
-  function langFromFilename(name) {{
-    const ext = (name.split('.').pop() || '').toLowerCase();
-    switch (ext) {{
-      case 'rs': return 'rust';
-      case 'py': return 'python';
-      case 'go': return 'go';
-      case 'js': return 'javascript';
-      case 'ts': return 'typescript';
-      case 'json': return 'json';
-      case 'md': return 'markdown';
-      case 'c': return 'c';
-      case 'h': return 'c';
-      case 'txt': return 'plaintext';
-      case 'santa': return 'plaintext';
-      default: return 'plaintext';
-    }}
-  }}
-  function setFile(name) {{
+  let currentFile = {init_name};
+  let lastClickedLine = null;
+
+  function renderFile(name) {{
     const fname = document.getElementById('code-filename');
     const code = document.getElementById('code-content');
     fname.textContent = name;
-    code.textContent = files[name] || '';
-    const lang = langFromFilename(name);
-    code.className = 'hljs language-' + lang;
+    code.innerHTML = files[name] || '';
+    currentFile = name;
     // Update active sidebar button
     document.querySelectorAll('[data-file]').forEach(btn => {{
       const isActive = btn.getAttribute('data-file') === name;
       btn.classList.toggle('bg-white/10', isActive);
       btn.classList.toggle('text-white', isActive);
     }});
-    // Re-highlight
-    if (window.hljs && window.hljs.highlightElement) {{ window.hljs.highlightElement(code); }}
   }}
+
+  function clearLineHighlight() {{
+    document.querySelectorAll('.code-line.line-highlight').forEach(el => el.classList.remove('line-highlight'));
+  }}
+
+  // Fragment format is `{{file}}:L{{n}}` or `{{file}}:L{{start}}-L{{end}}`, so `renderFile`'s
+  // client-side file switching doesn't lose which line a shared URL pointed at.
+  function parseHash(raw) {{
+    const m = raw.match(/^(.*?)(?::L(\d+)(?:-L(\d+))?)?$/);
+    if (!m) return null;
+    return {{ file: m[1], start: m[2] ? parseInt(m[2], 10) : null, end: m[3] ? parseInt(m[3], 10) : null }};
+  }}
+
+  function applyHash() {{
+    const raw = decodeURIComponent(window.location.hash.slice(1));
+    if (!raw) return;
+    const parsed = parseHash(raw);
+    if (!parsed || !(parsed.file in files)) return;
+    if (parsed.file !== currentFile) renderFile(parsed.file);
+    clearLineHighlight();
+    if (parsed.start) {{
+      const end = parsed.end || parsed.start;
+      lastClickedLine = parsed.start;
+      let first = null;
+      for (let n = parsed.start; n <= end; n++) {{
+        const el = document.getElementById('L' + n);
+        if (el) {{ el.classList.add('line-highlight'); if (!first) first = el; }}
+      }}
+      if (first) first.scrollIntoView({{ block: 'center' }});
+    }}
+  }}
+
   document.querySelectorAll('[data-file]').forEach(btn => {{
-    btn.addEventListener('click', () => setFile(btn.getAttribute('data-file')));
+    btn.addEventListener('click', () => {{
+      lastClickedLine = null;
+      window.location.hash = btn.getAttribute('data-file');
+    }});
   }});
+
+  // Clicking a gutter line number jumps to `#file:Ln`; shift-click extends the last clicked
+  // line into a `#file:Lstart-Lend` range, mirroring rustdoc's source-line anchors.
+  document.getElementById('code-content').addEventListener('click', (e) => {{
+    const link = e.target.closest('[data-line-link]');
+    if (!link) return;
+    e.preventDefault();
+    const n = parseInt(link.getAttribute('data-line-link'), 10);
+    if (e.shiftKey && lastClickedLine !== null) {{
+      const start = Math.min(lastClickedLine, n);
+      const end = Math.max(lastClickedLine, n);
+      window.location.hash = `${{currentFile}}:L${{start}}-L${{end}}`;
+    }} else {{
+      lastClickedLine = n;
+      window.location.hash = `${{currentFile}}:L${{n}}`;
+    }}
+  }});
+
+  window.addEventListener('hashchange', applyHash);
+
   const copyBtn = document.getElementById('copy-code');
   copyBtn?.addEventListener('click', async () => {{
     try {{
-      await navigator.clipboard.writeText(document.getElementById('code-content').textContent || '');
+      const text = Array.from(document.querySelectorAll('#code-content .code-line-text')).map(el => el.textContent).join('\n');
+      await navigator.clipboard.writeText(text);
       copyBtn.textContent = 'Copied!';
       setTimeout(() => copyBtn.textContent = 'Copy', 1200);
     }} catch (e) {{
@@ -802,12 +1821,13 @@ fn render_impl(imp: &ImplInfo, tree: &BTreeMap<String, String>, base_path: &str)
       setTimeout(() => copyBtn.textContent = 'Copy', 1200);
     }}
   }});
-  setFile({init_name});
+  renderFile(currentFile);
+  applyHash();
 </script>"#,
         sidebar = files_sidebar,
         fname = html_escape::encode_text(&initial_file.0),
-        content = html_escape::encode_text(&initial_file.1),
-        files_json = serde_json::to_string(&tree).unwrap(),
+        content = initial_html,
+        files_json = serde_json::to_string(&highlighted).unwrap(),
         init_name = serde_json::to_string(&initial_file.0).unwrap()
     );
 
@@ -830,10 +1850,6 @@ fn render_impl(imp: &ImplInfo, tree: &BTreeMap<String, String>, base_path: &str)
     tabs.forEach(t => t.classList.remove('tab-active'));
     if (which === 'journal') {{ j.style.display = ''; c.style.display = 'none'; tabs[0].classList.add('tab-active'); }}
     else {{ j.style.display = 'none'; c.style.display = ''; tabs[1].classList.add('tab-active'); }}
-    // Re-run syntax highlighting when switching tabs
-    if (window.hljs && window.hljs.highlightAll) {{
-      window.hljs.highlightAll();
-    }}
   }}
   document.querySelectorAll('.tab').forEach(b => {{
     b.addEventListener('click', () => showTab(b.getAttribute('data-tab')));
@@ -874,7 +1890,173 @@ fn render_impl(imp: &ImplInfo, tree: &BTreeMap<String, String>, base_path: &str)
         img = header_img_src
     );
 
-    layout_impl(&format!("{} – {}", imp.journal.details.language, author), &format!("{}{}", header, tabs), base_path)
+    let related_html = if related.is_empty() {
+        String::new()
+    } else {
+        let mut chips = String::new();
+        for r in related {
+            let r_author = if r.journal.author.trim().is_empty() { "Unknown Elf" } else { &r.journal.author };
+            chips.push_str(&format!(
+                r#"<a class="px-3 py-1.5 rounded-full bg-white/5 hover:bg-white/10 border border-white/10 text-sm text-white/80" href="{href}">{author} <span class="text-white/50">({lang})</span></a>"#,
+                href = base_url(base_path, &format!("impl/{}/", r.dir_name)),
+                author = html_escape::encode_text(r_author),
+                lang = html_escape::encode_text(&r.journal.details.language)
+            ));
+        }
+        format!(
+            r#"<div class="mt-6">
+  <div class="text-sm text-white/60 mb-2">Related implementations</div>
+  <div class="flex flex-wrap gap-2">{chips}</div>
+</div>"#,
+            chips = chips
+        )
+    };
+
+    let impl_feed_title = format!("{} — {}", author, imp.journal.details.language);
+    let impl_feed_href = base_url(base_path, &format!("impl/{}/atom.xml", imp.dir_name));
+    layout_impl(
+        &format!("{} – {}", imp.journal.details.language, author),
+        &format!("{}{}{}", header, related_html, tabs),
+        base_path,
+        &impl_feed_title,
+        &impl_feed_href,
+    )
+}
+
+/// Slug used for a comparison page's output directory, e.g. `rust-elf-vs-python-elf`.
+/// The pair is ordered alphabetically by `dir_name` so there is one canonical page per
+/// unordered pair, regardless of which implementation the user picked first/second.
+fn compare_slug(a: &str, b: &str) -> String {
+    if a <= b { format!("{}-vs-{}", a, b) } else { format!("{}-vs-{}", b, a) }
+}
+
+fn diff_rows_for_file(left: Option<&str>, right: Option<&str>) -> String {
+    let left_lines: Vec<&str> = left.map(|c| c.lines().collect()).unwrap_or_default();
+    let right_lines: Vec<&str> = right.map(|c| c.lines().collect()).unwrap_or_default();
+    let ops = myers_diff::diff_lines(&left_lines, &right_lines);
+
+    let mut rows = String::new();
+    let mut ln = 0usize;
+    let mut rn = 0usize;
+    for op in ops {
+        match op {
+            myers_diff::DiffOp::Equal(line) => {
+                ln += 1;
+                rn += 1;
+                let escaped = html_escape::encode_text(line);
+                rows.push_str(&format!(
+                    r#"<tr><td class="diff-ln">{ln}</td><td class="diff-cell diff-equal">{text}</td><td class="diff-ln">{rn}</td><td class="diff-cell diff-equal">{text}</td></tr>"#,
+                    ln = ln, rn = rn, text = escaped
+                ));
+            }
+            myers_diff::DiffOp::Delete(line) => {
+                ln += 1;
+                let escaped = html_escape::encode_text(line);
+                rows.push_str(&format!(
+                    r#"<tr><td class="diff-ln">{ln}</td><td class="diff-cell diff-del">{text}</td><td class="diff-ln"></td><td class="diff-cell"></td></tr>"#,
+                    ln = ln, text = escaped
+                ));
+            }
+            myers_diff::DiffOp::Insert(line) => {
+                rn += 1;
+                let escaped = html_escape::encode_text(line);
+                rows.push_str(&format!(
+                    r#"<tr><td class="diff-ln"></td><td class="diff-cell"></td><td class="diff-ln">{rn}</td><td class="diff-cell diff-ins">{text}</td></tr>"#,
+                    rn = rn, text = escaped
+                ));
+            }
+        }
+    }
+    rows
+}
+
+/// Renders the two-column diff page comparing `a` and `b`'s code trees, pairing up files
+/// that share the same relative path and diffing them with [`myers_diff`]. Files present in
+/// only one side are rendered as wholly deleted/inserted.
+fn render_compare_page(
+    a: &ImplInfo,
+    a_tree: &BTreeMap<String, String>,
+    b: &ImplInfo,
+    b_tree: &BTreeMap<String, String>,
+    base_path: &str,
+) -> String {
+    let mut rels: BTreeSet<&str> = a_tree.keys().map(|s| s.as_str()).collect();
+    rels.extend(b_tree.keys().map(|s| s.as_str()));
+
+    let mut files_sidebar = String::new();
+    let mut diff_panels = String::new();
+    for (i, rel) in rels.iter().enumerate() {
+        let left = a_tree.get(*rel).map(|s| s.as_str());
+        let right = b_tree.get(*rel).map(|s| s.as_str());
+        let status = match (left, right) {
+            (Some(_), Some(_)) => "",
+            (Some(_), None) => " (removed)",
+            (None, Some(_)) => " (added)",
+            (None, None) => unreachable!(),
+        };
+        files_sidebar.push_str(&format!(
+            r#"<button class="w-full text-left px-3 py-1.5 rounded hover:bg-white/10 code text-xs" data-diff-file="diff-file-{i}">{rel}{status}</button>"#,
+            i = i,
+            rel = html_escape::encode_text(rel),
+            status = status
+        ));
+        diff_panels.push_str(&format!(
+            r#"<div class="diff-file" id="diff-file-{i}" style="display:{display}">
+  <div class="px-4 py-2 text-xs text-white/60 border-b border-white/10 bg-black/30 flex items-center justify-between">
+    <span>{rel}{status}</span>
+    <div class="flex gap-4 text-white/40">
+      <span>{a_name}</span>
+      <span>{b_name}</span>
+    </div>
+  </div>
+  <table class="diff-table text-sm p-0 overflow-auto bg-black/30"><tbody>
+    {rows}
+  </tbody></table>
+</div>"#,
+            i = i,
+            display = if i == 0 { "" } else { "none" },
+            rel = html_escape::encode_text(rel),
+            status = status,
+            a_name = html_escape::encode_text(&a.dir_name),
+            b_name = html_escape::encode_text(&b.dir_name),
+            rows = diff_rows_for_file(left, right)
+        ));
+    }
+
+    let body = format!(
+        r#"<div class="flex items-center gap-3 mb-6">
+  <h1 class="text-2xl font-semibold">Comparing <a class="underline hover:text-white/80" href="{a_href}">{a_name}</a> vs <a class="underline hover:text-white/80" href="{b_href}">{b_name}</a></h1>
+</div>
+<div class="grid grid-cols-1 lg:grid-cols-4 gap-4">
+  <aside class="paper-dark rounded-lg p-3 border border-white/10 lg:col-span-1 max-h-[70vh] overflow-auto">
+    {sidebar}
+  </aside>
+  <section class="paper-dark rounded-lg p-0 border border-white/10 lg:col-span-3 overflow-auto max-h-[70vh]">
+    {panels}
+  </section>
+</div>
+<script>
+  document.querySelectorAll('[data-diff-file]').forEach(btn => {{
+    btn.addEventListener('click', () => {{
+      document.querySelectorAll('.diff-file').forEach(el => el.style.display = 'none');
+      document.getElementById(btn.getAttribute('data-diff-file')).style.display = '';
+      document.querySelectorAll('[data-diff-file]').forEach(b => {{
+        const isActive = b === btn;
+        b.classList.toggle('bg-white/10', isActive);
+        b.classList.toggle('text-white', isActive);
+      }});
+    }});
+  }});
+</script>"#,
+        a_href = base_url(base_path, &format!("impl/{}/", a.dir_name)),
+        b_href = base_url(base_path, &format!("impl/{}/", b.dir_name)),
+        a_name = html_escape::encode_text(&a.dir_name),
+        b_name = html_escape::encode_text(&b.dir_name),
+        sidebar = files_sidebar,
+        panels = diff_panels
+    );
+
+    layout(&format!("Compare: {} vs {}", a.dir_name, b.dir_name), &body, base_path)
 }
 
 fn collect_code_tree(root: &Path, exclude_dirs: &[&str]) -> Result<BTreeMap<String, String>, String> {
@@ -906,7 +2088,10 @@ fn collect_code_tree(root: &Path, exclude_dirs: &[&str]) -> Result<BTreeMap<Stri
     Ok(map)
 }
 
-fn copy_assets(out_dir: &Path, impls: &[ImplInfo]) -> Result<(), String> {
+/// Copies the site-wide logo assets to `out_dir`. Per-impl `elf.png` assets are copied
+/// alongside their page in `main`'s per-impl loop instead, gated by [`InputManifest`] so an
+/// unchanged implementation skips the recopy.
+fn copy_assets(out_dir: &Path) -> Result<(), String> {
     // Copy root logo-light.png to docs/
     let root_logo = repo_root().join("logo-light.png");
     if root_logo.exists() {
@@ -921,55 +2106,525 @@ fn copy_assets(out_dir: &Path, impls: &[ImplInfo]) -> Result<(), String> {
         if let Some(parent) = dst.parent() { ensure_dir(parent)?; }
         fs::copy(&unknown_elf, &dst).map_err(|e| format!("Failed to copy {} -> {}: {}", unknown_elf.display(), dst.display(), e))?;
     }
+    Ok(())
+}
+
+/// `size:mtime` of `path`'s metadata, or empty string if it can't be read. Cheap stand-in
+/// for hashing the image bytes themselves when folding an impl's `elf.png` into its input hash.
+fn file_meta_fingerprint(path: &Path) -> String {
+    fs::metadata(path)
+        .ok()
+        .map(|m| {
+            let mtime = m.modified().ok().and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok()).map(|d| d.as_secs()).unwrap_or(0);
+            format!("{}:{}", m.len(), mtime)
+        })
+        .unwrap_or_default()
+}
+
+/// Splits a markdown doc into (level, title, slug, body_text) sections on H2+ headings,
+/// skipping fenced code blocks. Inline markdown in the remaining lines is kept as written.
+fn extract_md_sections(md: &str) -> Vec<(usize, String, String, String)> {
+    let mut sections: Vec<(usize, String, String, String)> = Vec::new();
+    let mut in_fence = false;
+    for line in md.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if !in_fence && trimmed.starts_with('#') {
+            let level = trimmed.chars().take_while(|c| *c == '#').count();
+            if level >= 2 {
+                let title = trimmed[level..].trim();
+                if !title.is_empty() {
+                    sections.push((level, title.to_string(), slugify(title), String::new()));
+                    continue;
+                }
+            }
+        }
+        if in_fence { continue; }
+        if let Some(last) = sections.last_mut() {
+            last.3.push_str(trimmed);
+            last.3.push(' ');
+        }
+    }
+    sections
+}
+
+fn tokenize(s: &str) -> Vec<String> {
+    s.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_string())
+        .collect()
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct ImplIndexMeta {
+    dir_name: String,
+    author: String,
+    lang: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct ImplIndex {
+    impls: Vec<ImplIndexMeta>,
+    /// Inverted index: token -> sorted, deduplicated indices into `impls`.
+    tokens: BTreeMap<String, Vec<usize>>,
+}
+
+/// Builds the inverted index behind the showcase grid's free-text search box (the
+/// `filter-text` input in `render_index`), in the spirit of rustdoc's `search_index.rs`:
+/// tokenize each implementation's author, language, harness, model, journal prose and
+/// filenames, and map each token to the set of implementation indices it appears in.
+/// This is distinct from `build_search_index`'s `search-index.json`, which powers the
+/// header's full-text modal over implementations and spec sections instead.
+fn build_impl_index(impls: &[ImplInfo], code_trees: &[BTreeMap<String, String>]) -> ImplIndex {
+    let mut meta = Vec::with_capacity(impls.len());
+    let mut tokens: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+
+    for (i, ii) in impls.iter().enumerate() {
+        let d = &ii.journal.details;
+        meta.push(ImplIndexMeta {
+            dir_name: ii.dir_name.clone(),
+            author: ii.journal.author.clone(),
+            lang: d.language.clone(),
+        });
+
+        let mut text = format!("{} {} {} {} {}", ii.journal.author, d.language, d.harness, d.model, ii.dir_name);
+        for e in &ii.journal.journal {
+            text.push(' ');
+            text.push_str(&e.entry);
+        }
+        for rel in code_trees[i].keys() {
+            text.push(' ');
+            text.push_str(rel);
+        }
+
+        for token in tokenize(&text) {
+            let postings = tokens.entry(token).or_default();
+            if postings.last() != Some(&i) {
+                postings.push(i);
+            }
+        }
+    }
+
+    ImplIndex { impls: meta, tokens }
+}
+
+/// Builds the client-side search index: one doc per implementation (author, stack,
+/// requirements, journal text) plus one doc per LANG.md/TASKS.md section.
+fn build_search_index(impls: &[ImplInfo], root: &Path, base_path: &str) -> Vec<SearchDoc> {
+    let mut docs = Vec::new();
+
     for ii in impls {
-        if let Some(src) = &ii.elf_png_path {
-            let dst = out_dir.join("impl").join(&ii.dir_name).join("elf.png");
-            if let Some(parent) = dst.parent() { ensure_dir(parent)?; }
-            fs::copy(src, &dst).map_err(|e| format!("Failed to copy {} -> {}: {}", src.display(), dst.display(), e))?;
+        let d = &ii.journal.details;
+        let mut text = format!("{} {} {} {} {}", ii.journal.author, d.language, d.harness, d.model, d.requirements);
+        for e in &ii.journal.journal {
+            text.push(' ');
+            text.push_str(&e.entry);
+        }
+        docs.push(SearchDoc {
+            id: format!("impl:{}", ii.dir_name),
+            title: format!("{} ({} / {})", ii.dir_name, d.language, d.harness),
+            url: base_url(base_path, &format!("impl/{}/", ii.dir_name)),
+            kind: "implementation".to_string(),
+            text,
+        });
+    }
+
+    let lang_path = root.join("specs").join("LANG.md");
+    if let Ok(md) = fs::read_to_string(&lang_path) {
+        for (_, title, id, text) in extract_md_sections(&md) {
+            docs.push(SearchDoc {
+                id: format!("language:{}", id),
+                title,
+                url: format!("{}#{}", base_url(base_path, "language/"), id),
+                kind: "language".to_string(),
+                text,
+            });
         }
     }
-    Ok(())
+
+    let tasks_path = root.join("specs").join("TASKS.md");
+    if let Ok(md) = fs::read_to_string(&tasks_path) {
+        for (_, title, id, text) in extract_md_sections(&md) {
+            docs.push(SearchDoc {
+                id: format!("tasks:{}", id),
+                title,
+                url: format!("{}#{}", base_url(base_path, "tasks/"), id),
+                kind: "tasks".to_string(),
+                text,
+            });
+        }
+    }
+
+    docs
+}
+
+struct FeedEntry {
+    title: String,
+    /// RFC3339 timestamp, taken verbatim from `JournalEntry::written_at`.
+    updated: String,
+    /// Page the entry links back to (no per-entry anchor exists in the code browser UI).
+    link: String,
+    content_html: String,
+}
+
+/// Renders a journal entry's markdown body to HTML for feed consumption, without any of
+/// the client-side mermaid/hljs affordances the website itself relies on.
+fn render_entry_html(entry: &str) -> String {
+    let mut opts = MdOptions::empty();
+    opts.insert(MdOptions::ENABLE_TABLES);
+    opts.insert(MdOptions::ENABLE_FOOTNOTES);
+    let parser = MdParser::new_ext(entry, opts);
+    let mut out = String::new();
+    md_html::push_html(&mut out, parser);
+    out
+}
+
+fn feed_entries_for_impl(imp: &ImplInfo, impl_url: &str) -> Vec<FeedEntry> {
+    let mut entries: Vec<FeedEntry> = imp
+        .journal
+        .journal
+        .iter()
+        .map(|e| FeedEntry {
+            title: format!("{} — {}", imp.dir_name, imp.journal.details.language),
+            updated: e.written_at.clone(),
+            link: impl_url.to_string(),
+            content_html: render_entry_html(&e.entry),
+        })
+        .collect();
+    entries.sort_by(|a, b| b.updated.cmp(&a.updated));
+    entries
 }
 
+/// Builds an Atom 1.0 feed (RFC 4287) from `entries`, which must already be sorted
+/// newest-first; the feed's own `<updated>` is the first entry's timestamp. No `<author>`
+/// element is written, at feed or entry level.
+fn render_atom_feed(feed_title: &str, feed_url: &str, entries: &[FeedEntry]) -> String {
+    let updated = entries.first().map(|e| e.updated.as_str()).unwrap_or("1970-01-01T00:00:00Z");
+    let mut entries_xml = String::new();
+    for (i, e) in entries.iter().enumerate() {
+        entries_xml.push_str(&format!(
+            r#"  <entry>
+    <title>{title}</title>
+    <link href="{link}"/>
+    <id>{id}</id>
+    <updated>{updated}</updated>
+    <content type="html">{content}</content>
+  </entry>
+"#,
+            title = html_escape::encode_text(&e.title),
+            link = html_escape::encode_double_quoted_attribute(&e.link),
+            id = html_escape::encode_text(&format!("{}#entry-{}", e.link, i)),
+            updated = html_escape::encode_text(&e.updated),
+            content = html_escape::encode_text(&e.content_html)
+        ));
+    }
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>{title}</title>
+  <link href="{feed_url}" rel="self"/>
+  <id>{feed_url}</id>
+  <updated>{updated}</updated>
+{entries}</feed>
+"#,
+        title = html_escape::encode_text(feed_title),
+        feed_url = html_escape::encode_double_quoted_attribute(feed_url),
+        updated = html_escape::encode_text(updated),
+        entries = entries_xml
+    )
+}
+
+const SEARCH_JS: &str = r#"(function() {
+  const panel = document.getElementById('search-panel');
+  const input = document.getElementById('search-input');
+  const results = document.getElementById('search-results');
+  const script = document.currentScript;
+  if (!panel || !input || !results || !script) return;
+
+  const indexUrl = script.getAttribute('data-search-index');
+  let docs = null;
+  // token -> Map(doc index -> term frequency in that doc)
+  let postings = null;
+
+  function tokenize(s) {
+    return (s.toLowerCase().match(/[a-z0-9]+/g) || []);
+  }
+
+  function buildPostings(docs) {
+    const index = new Map();
+    docs.forEach((doc, i) => {
+      const tf = new Map();
+      for (const t of tokenize(doc.title + ' ' + doc.text)) {
+        tf.set(t, (tf.get(t) || 0) + 1);
+      }
+      for (const [t, count] of tf) {
+        let docCounts = index.get(t);
+        if (!docCounts) { docCounts = new Map(); index.set(t, docCounts); }
+        docCounts.set(i, count);
+      }
+    });
+    return index;
+  }
+
+  function ensureLoaded() {
+    if (docs) return Promise.resolve(docs);
+    return fetch(indexUrl).then(r => r.json()).then(data => {
+      docs = data;
+      postings = buildPostings(docs);
+      return docs;
+    });
+  }
+
+  function snippet(text, terms) {
+    const lower = text.toLowerCase();
+    let at = -1;
+    for (const t of terms) {
+      const idx = lower.indexOf(t);
+      if (idx !== -1) { at = idx; break; }
+    }
+    if (at === -1) at = 0;
+    const start = Math.max(0, at - 40);
+    const end = Math.min(text.length, at + 120);
+    return (start > 0 ? '…' : '') + text.slice(start, end).trim() + (end < text.length ? '…' : '');
+  }
+
+  function render(matches, terms) {
+    if (matches.length === 0) {
+      results.innerHTML = '<div class="px-4 py-6 text-sm text-white/40">No results.</div>';
+      return;
+    }
+    results.innerHTML = matches.slice(0, 20).map(m => `
+      <a href="${m.doc.url}" class="block px-4 py-3 hover:bg-white/5">
+        <div class="text-sm text-white/90">${m.doc.title}</div>
+        <div class="text-xs text-white/40 uppercase tracking-wide">${m.doc.kind}</div>
+        <div class="text-xs text-white/50 mt-1">${snippet(m.doc.text, terms)}</div>
+      </a>
+    `).join('');
+  }
+
+  // Whole-token intersection: a doc only matches if every query term appears
+  // somewhere in its postings, then it's scored by summed term frequency.
+  function search(query) {
+    const terms = tokenize(query);
+    if (terms.length === 0) { results.innerHTML = ''; return; }
+
+    let candidates = null;
+    for (const t of terms) {
+      const docCounts = postings.get(t);
+      const ids = docCounts ? new Set(docCounts.keys()) : new Set();
+      candidates = candidates === null ? ids : new Set([...candidates].filter(id => ids.has(id)));
+      if (candidates.size === 0) break;
+    }
+
+    const matches = [];
+    for (const id of candidates || []) {
+      const doc = docs[id];
+      let score = 0;
+      for (const t of terms) {
+        score += postings.get(t).get(id);
+      }
+      const titleTerms = new Set(tokenize(doc.title));
+      if (terms.some(t => titleTerms.has(t))) score += 5;
+      matches.push({ doc, score });
+    }
+    matches.sort((a, b) => b.score - a.score);
+    render(matches, terms);
+  }
+
+  input.addEventListener('input', () => {
+    ensureLoaded().then(() => search(input.value));
+  });
+
+  panel.addEventListener('transitionend', () => {});
+  document.getElementById('search-open')?.addEventListener('click', () => { ensureLoaded(); });
+})();
+"#;
+
+const THEME_JS: &str = r#"(function() {
+  const THEMES = ['dark', 'light', 'ayu'];
+  const HLJS_STYLE = { dark: 'github-dark', light: 'github', ayu: 'tomorrow-night-bright' };
+
+  function swapHljsStylesheet(theme) {
+    const link = document.getElementById('hljs-theme');
+    if (!link) return;
+    const style = HLJS_STYLE[theme] || HLJS_STYLE.dark;
+    link.href = link.href.replace(/styles\/[^/]+\.min\.css$/, `styles/${style}.min.css`);
+  }
+
+  window.setSiteTheme = function(theme) {
+    if (!THEMES.includes(theme)) return;
+    document.documentElement.setAttribute('data-theme', theme);
+    swapHljsStylesheet(theme);
+    try { localStorage.setItem('site-theme', theme); } catch (e) {}
+  };
+
+  swapHljsStylesheet(document.documentElement.getAttribute('data-theme') || 'dark');
+})();
+"#;
+
 fn main() -> Result<(), String> {
     let args = Args::parse();
     let root = repo_root();
     let impl_dir = args.impl_dir.unwrap_or(root.join("impl"));
     let out_dir = args.out_dir.unwrap_or(root.join("docs"));
     let base_path = args.base_path.unwrap_or_default();
+    let site_url = args.site_url.unwrap_or_default();
 
     let impls = read_impls(&impl_dir)?;
     ensure_dir(&out_dir)?;
+    let mut cache = BuildCache::load(&out_dir, args.force);
+    let mut input_manifest = InputManifest::load(&out_dir, args.force);
+    let highlighter = code_highlight::Highlighter::new();
+    write_file(&mut cache, &out_dir.join("syntax.css"), &code_highlight::theme_css())?;
 
-    // index.html
+    // Collected once up front so both the showcase search index and the per-impl
+    // code browser pages below can reuse the same file listing.
+    let code_trees: Vec<BTreeMap<String, String>> = impls
+        .iter()
+        .map(|ii| collect_code_tree(&ii.abs_path, &["target", "__pycache__", "node_modules", "venv", "env", "build", "dist"]))
+        .collect::<Result<_, _>>()?;
+
+    // index.html — keyed on every impl's showcase-card fields plus base_path (baked into
+    // every emitted URL), so adding/editing an implementation or rebuilding with a
+    // different --base-path invalidates it, but unrelated site edits don't.
     let intro = read_readme_intro(&root);
-    let index_html = render_index(&impls, &intro, &base_path);
-    write_file(&out_dir.join("index.html"), &index_html)?;
-
-    // language page
-    let lang_html = render_language_page(&root, &base_path);
-    let lang_dir = out_dir.join("language");
-    ensure_dir(&lang_dir)?;
-    write_file(&lang_dir.join("index.html"), &lang_html)?;
-
-    // tasks page
-    let tasks_html = render_tasks_page(&root, &base_path);
-    let tasks_dir = out_dir.join("tasks");
-    ensure_dir(&tasks_dir)?;
-    write_file(&tasks_dir.join("index.html"), &tasks_html)?;
-
-    // per-impl pages and code assets
-    for ii in &impls {
-        let code_tree = collect_code_tree(&ii.abs_path, &["target", "__pycache__", "node_modules", "venv", "env", "build", "dist"])?;
-        let html = render_impl(ii, &code_tree, &base_path);
+    let index_input = format!(
+        "{}|{}|{}",
+        intro,
+        impls
+            .iter()
+            .map(|ii| format!("{}:{}:{}:{}:{}:{}", ii.dir_name, ii.journal.author, ii.journal.details.language, ii.journal.details.harness, ii.journal.details.model, ii.elf_png_path.is_some()))
+            .collect::<Vec<_>>()
+            .join(","),
+        base_path
+    );
+    if input_manifest.changed("index", &index_input) {
+        let index_html = render_index(&impls, &intro, &base_path);
+        write_file(&mut cache, &out_dir.join("index.html"), &index_html)?;
+    }
+
+    // Inverted token index powering the showcase grid's free-text search box.
+    let impl_index = build_impl_index(&impls, &code_trees);
+    let impl_index_json = serde_json::to_string(&impl_index).map_err(|e| e.to_string())?;
+    write_file(&mut cache, &out_dir.join("impl-index.json"), &impl_index_json)?;
+
+    // language page, keyed on specs/LANG.md's own content plus base_path
+    let lang_path = root.join("specs").join("LANG.md");
+    let lang_input = format!("{}|{}", fs::read_to_string(&lang_path).unwrap_or_default(), base_path);
+    if input_manifest.changed("language", &lang_input) {
+        let lang_html = render_language_page(&root, &base_path);
+        let lang_dir = out_dir.join("language");
+        ensure_dir(&lang_dir)?;
+        write_file(&mut cache, &lang_dir.join("index.html"), &lang_html)?;
+    }
+
+    // tasks page, keyed on specs/TASKS.md's own content plus base_path
+    let tasks_path = root.join("specs").join("TASKS.md");
+    let tasks_input = format!("{}|{}", fs::read_to_string(&tasks_path).unwrap_or_default(), base_path);
+    if input_manifest.changed("tasks", &tasks_input) {
+        let tasks_html = render_tasks_page(&root, &base_path);
+        let tasks_dir = out_dir.join("tasks");
+        ensure_dir(&tasks_dir)?;
+        write_file(&mut cache, &tasks_dir.join("index.html"), &tasks_html)?;
+    }
+
+    // Bag-of-words per impl (journal prose + source tree) feeding the "Related
+    // implementations" strip on each per-impl page below.
+    let tfidf_docs: Vec<String> = impls
+        .iter()
+        .zip(&code_trees)
+        .map(|(ii, tree)| {
+            let mut text = ii.journal.journal.iter().map(|e| e.entry.as_str()).collect::<Vec<_>>().join(" ");
+            for content in tree.values() {
+                text.push(' ');
+                text.push_str(content);
+            }
+            text
+        })
+        .collect();
+    let related_indices = tfidf::related(&tfidf_docs);
+    // The "Related implementations" strip on every impl page is computed from every other
+    // impl's tfidf doc, not just this impl's own inputs — fold the whole corpus into a single
+    // fingerprint so editing/adding/removing a sibling invalidates this impl's page too.
+    let related_corpus_fingerprint = tfidf_docs.join("\u{0}");
+
+    // per-impl pages, code assets and feeds
+    let mut all_feed_entries: Vec<FeedEntry> = Vec::new();
+    for (i, (ii, code_tree)) in impls.iter().zip(&code_trees).enumerate() {
         let impl_dir_out = out_dir.join("impl").join(&ii.dir_name);
         ensure_dir(&impl_dir_out)?;
-        write_file(&impl_dir_out.join("index.html"), &html)?;
+
+        // Keyed on the journal, the full code tree, the elf.png's size/mtime, base_path
+        // (baked into every emitted URL) and the related-implementations corpus fingerprint
+        // (the "Related implementations" strip depends on every other impl, not just this
+        // one), so an implementation whose inputs haven't moved since the last build skips
+        // the markdown-render + syntax-highlight pass entirely, not just the file write.
+        let impl_input = format!(
+            "{}|{}|{}|{}|{}",
+            serde_json::to_string(&ii.journal).map_err(|e| e.to_string())?,
+            code_tree.values().map(|s| s.as_str()).collect::<Vec<_>>().join("\u{0}"),
+            ii.elf_png_path.as_ref().map(|p| file_meta_fingerprint(p)).unwrap_or_default(),
+            base_path,
+            related_corpus_fingerprint
+        );
+        if input_manifest.changed(&format!("impl:{}", ii.dir_name), &impl_input) {
+            let related: Vec<&ImplInfo> = related_indices[i].iter().map(|&(j, _)| &impls[j]).collect();
+            let html = render_impl(ii, code_tree, &base_path, &highlighter, &related);
+            write_file(&mut cache, &impl_dir_out.join("index.html"), &html)?;
+            if let Some(src) = &ii.elf_png_path {
+                let dst = impl_dir_out.join("elf.png");
+                fs::copy(src, &dst).map_err(|e| format!("Failed to copy {} -> {}: {}", src.display(), dst.display(), e))?;
+            }
+        }
+
+        let impl_url = absolute_url(&site_url, &base_path, &format!("impl/{}/", ii.dir_name));
+        let impl_entries = feed_entries_for_impl(ii, &impl_url);
+        if !impl_entries.is_empty() {
+            let impl_feed_url = absolute_url(&site_url, &base_path, &format!("impl/{}/atom.xml", ii.dir_name));
+            let impl_feed = render_atom_feed(
+                &format!("{} — {}", ii.dir_name, ii.journal.details.language),
+                &impl_feed_url,
+                &impl_entries,
+            );
+            write_file(&mut cache, &impl_dir_out.join("atom.xml"), &impl_feed)?;
+        }
+        all_feed_entries.extend(impl_entries);
+    }
+
+    // Side-by-side comparison pages, one per unordered pair of implementations
+    for i in 0..impls.len() {
+        for j in (i + 1)..impls.len() {
+            let (a, b) = (&impls[i], &impls[j]);
+            let compare_html = render_compare_page(a, &code_trees[i], b, &code_trees[j], &base_path);
+            let compare_dir = out_dir.join("compare").join(compare_slug(&a.dir_name, &b.dir_name));
+            ensure_dir(&compare_dir)?;
+            write_file(&mut cache, &compare_dir.join("index.html"), &compare_html)?;
+        }
     }
 
-    // Copy images per impl
-    copy_assets(&out_dir, &impls)?;
+    // Site-wide Atom feed aggregating every implementation's journal entries
+    all_feed_entries.sort_by(|a, b| b.updated.cmp(&a.updated));
+    let site_feed_url = absolute_url(&site_url, &base_path, "atom.xml");
+    let site_feed = render_atom_feed("santa-lang Workshop Journal", &site_feed_url, &all_feed_entries);
+    write_file(&mut cache, &out_dir.join("atom.xml"), &site_feed)?;
+
+    // Copy site-wide logo assets (per-impl elf.png assets are copied above, gated by
+    // input_manifest alongside their page)
+    copy_assets(&out_dir)?;
+
+    // Search index and client-side search script
+    let search_docs = build_search_index(&impls, &root, &base_path);
+    let search_index_json = serde_json::to_string(&search_docs).map_err(|e| e.to_string())?;
+    write_file(&mut cache, &out_dir.join("search-index.json"), &search_index_json)?;
+    write_file(&mut cache, &out_dir.join("search.js"), SEARCH_JS)?;
+    write_file(&mut cache, &out_dir.join("theme.js"), THEME_JS)?;
 
-    println!("Site generated at {}", out_dir.display());
+    cache.save(&out_dir)?;
+    input_manifest.save(&out_dir)?;
+    println!("Site generated at {} ({}, {})", out_dir.display(), cache.summary(), input_manifest.summary());
     Ok(())
 }
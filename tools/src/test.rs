@@ -6,7 +6,8 @@ use std::fs;
 use std::env;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use std::time::Duration;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 use tempfile::TempDir;
 use wait_timeout::ChildExt;
 
@@ -22,11 +23,22 @@ It parses .santat files containing test definitions with sections like:
   --EXPECT--      Expected standard output
   --EXPECT_AST--  Expected AST representation
   --EXPECT_TOKENS-- Expected token stream
+  --EXPECT_ERROR-- Expected standard error (trimmed)
+  --EXPECT_EXIT--  Expected process exit code
+  --NORMALIZE--   Extra `PATTERN => REPLACEMENT` regex rules applied to expected/actual
+                  output before comparison, on top of the built-in temp-dir, memory-address
+                  and path-separator redactions
   --TEST--        Test description (optional)
 
 The tool runs the Santa compiler/interpreter with different modes and compares
 the actual output against expected results, showing detailed diffs for failures.
 
+A file may declare named revisions to assert several modes/inputs against the same
+--FILE-- without duplicating it, e.g. --EXPECT[release]-- / --EXPECT[trace]-- alongside
+an optional --ARGS[release]-- line of extra CLI args appended for that revision. Each
+revision is run and reported independently; a file with no bracketed sections runs as a
+single unnamed revision, same as before.
+
 Examples:
   santa-test --bin ./cli tests/
   santa-test --bin docker://edd/santa-go:cli tests/
@@ -40,6 +52,25 @@ Docker notes:
   accordingly. The container is started with -i (and -t if stdout is a TTY). You can
   pass additional docker run flags via the SANTA_DOCKER_FLAGS environment variable,
   e.g. SANTA_DOCKER_FLAGS="--network host --cpus 2" santa-test --bin docker://image:tag tests/
+
+Reporters:
+  --reporter pretty (default) prints colored ✓/✗ per check, same as running with no --reporter.
+  --reporter junit  writes a JUnit XML <testsuite> document, one <testcase> per .santat file.
+  --reporter json   writes a JSON array with per-check pass/fail and diffs, and each file's duration.
+  Both junit and json print to stdout by default; pass --reporter-output <path> to write to a
+  file instead, e.g. for a CI job to upload as a test report artifact.
+
+  --shuffle [SEED] randomizes test execution (and reporting) order with a seeded PRNG, to
+  surface tests that secretly depend on filesystem ordering or shared CWD state. The seed used
+  is always printed as `Shuffle seed: N`; pass it back via --shuffle N to reproduce a run.
+
+  --watch re-runs the suite on change: after the initial run it polls the given targets (and,
+  for a local --bin, the binary itself) and re-runs only the .santat files that changed, or the
+  whole suite when the binary is rebuilt. The screen is cleared between runs. Exit with Ctrl+C.
+
+  Under GitHub Actions (GITHUB_ACTIONS set, or pass --github-annotations to force it), each
+  failing check additionally prints a `::error file=...::...` workflow command so the failure
+  is annotated inline on the PR diff, alongside whatever --reporter was selected.
 "#)]
 struct Args {
     #[arg(short, long, help = "Path to the Santa CLI executable or docker image URI (docker://image:tag)")]
@@ -57,10 +88,71 @@ struct Args {
     #[arg(short, long, help = "Number of parallel jobs (0 = auto-detect CPU count)")]
     jobs: Option<usize>,
 
+    #[arg(long, value_enum, default_value_t = Reporter::Pretty, help = "Result output format")]
+    reporter: Reporter,
+
+    #[arg(long, help = "Write --reporter junit/json output to this file instead of stdout")]
+    reporter_output: Option<PathBuf>,
+
+    #[arg(long, num_args = 0..=1, value_name = "SEED", help = "Shuffle test execution order, optionally with a reproducible u64 seed")]
+    shuffle: Option<Option<u64>>,
+
+    #[arg(long, help = "Watch the targets (and a local --bin) and re-run affected tests on change")]
+    watch: bool,
+
+    #[arg(long, help = "Emit GitHub Actions error annotations for failing checks (auto-enabled when GITHUB_ACTIONS is set)")]
+    github_annotations: bool,
+
     #[arg(help = "Test files or directories to run", required = true)]
     targets: Vec<PathBuf>,
 }
 
+/// How to report collected `TestResult`s once every test has finished running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Reporter {
+    Pretty,
+    Junit,
+    Json,
+}
+
+/// A small, fast, seedable PRNG (SplitMix64) used only to deterministically shuffle test
+/// execution order — not suitable for anything security-sensitive.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Fisher-Yates shuffle of `items` using a PRNG seeded from `seed`, so the same seed always
+/// produces the same order.
+fn shuffle_with_seed<T>(items: &mut [T], seed: u64) {
+    let mut rng = SplitMix64::new(seed);
+    for i in (1..items.len()).rev() {
+        let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+/// A seed good enough to pick a shuffle order when the user didn't supply one; not
+/// cryptographically secure, just varies run to run.
+fn random_seed() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+    (nanos as u64) ^ (std::process::id() as u64).wrapping_mul(0x9E3779B97F4A7C15)
+}
+
 /// How to run the target CLI (either a host path or a docker image)
 #[derive(Debug, Clone)]
 enum Runner {
@@ -134,18 +226,59 @@ impl Runner {
 #[derive(Debug)]
 struct TestBlock {
     name: String,
+    /// Revision suffix from `--NAME[revision]--`, e.g. `Some("release")`; `None` for a plain
+    /// `--NAME--` section.
+    revision: Option<String>,
     content: String,
 }
 
+/// One named (or the implicit unnamed) run of a `.santat` file: its own expected sections and
+/// extra CLI args, from that revision's `--EXPECT*[name]--` / `--ARGS[name]--` blocks.
+#[derive(Debug)]
+struct Revision {
+    name: Option<String>,
+    args: Vec<String>,
+    expects: HashMap<String, String>,
+}
+
 #[derive(Debug)]
 struct SantatFile {
     blocks: Vec<TestBlock>,
     map: HashMap<String, String>,
+    revisions: Vec<Revision>,
+}
+
+/// Which part of a test run's output a `checks` entry compares against its `--EXPECT*--` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckField {
+    Stdout,
+    Stderr,
+    ExitCode,
+}
+
+/// The outcome of one `--EXPECT*--` comparison within a revision, kept around so `--reporter
+/// junit`/`json` can surface per-check detail instead of just the pretty-printed text.
+#[derive(Debug)]
+struct CheckResult {
+    /// The section it checked, e.g. "EXPECT", "EXPECT_AST".
+    kind: String,
+    /// The revision it ran under (`None` for the unnamed revision).
+    revision: Option<String>,
+    passed: bool,
+    timed_out: bool,
+    /// The unified diff against expected output on a mismatch, or the error message when the
+    /// CLI could not be run; `None` on a pass or a timeout.
+    diff: Option<String>,
 }
 
 #[derive(Debug)]
 struct TestResult {
+    name: String,
+    path: PathBuf,
     passed: bool,
+    duration: Duration,
+    checks: Vec<CheckResult>,
+    /// Pre-rendered text for the pretty reporter.
     output: String,
 }
 
@@ -180,40 +313,93 @@ fn normalize_newlines(s: &str) -> String {
     s.replace("\r\n", "\n")
 }
 
+/// Builds the regex substitution rules used to scrub run-to-run-variable output (this test's
+/// own temp dir, memory addresses, Windows path separators) before comparing expected/actual
+/// text, so a test run on one machine or inside the docker runner matches a fixture captured
+/// on another. `normalize_section` is the raw `--NORMALIZE--` body, one `PATTERN => REPLACEMENT`
+/// regex rule per line, applied after the built-ins so it can further refine their output.
+fn build_normalize_rules(
+    temp_dir: &Path,
+    normalize_section: Option<&str>,
+) -> Result<Vec<(regex::Regex, String)>, Box<dyn std::error::Error>> {
+    let mut rules: Vec<(regex::Regex, String)> = vec![
+        (regex::Regex::new(r"\\")?, "/".to_string()),
+        // The trailing \b leaves an already-redacted `0xADDR` (e.g. written by --update) as is.
+        (regex::Regex::new(r"0x[0-9a-fA-F]+\b")?, "0xADDR".to_string()),
+    ];
+
+    let temp_dir_str = temp_dir.to_string_lossy().replace('\\', "/");
+    rules.push((regex::Regex::new(&regex::escape(&temp_dir_str))?, "$TEST_DIR".to_string()));
+
+    if let Some(raw) = normalize_section {
+        for line in raw.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (pattern, replacement) = line.split_once("=>").ok_or_else(|| {
+                format!("invalid --NORMALIZE-- rule (expected `PATTERN => REPLACEMENT`): {}", line)
+            })?;
+            rules.push((regex::Regex::new(pattern.trim())?, replacement.trim().to_string()));
+        }
+    }
+
+    Ok(rules)
+}
+
+/// Applies `rules` in order; each replacement is literal text, so `$TEST_DIR` is not read as a
+/// capture-group reference.
+fn apply_normalization(text: &str, rules: &[(regex::Regex, String)]) -> String {
+    rules
+        .iter()
+        .fold(text.to_string(), |acc, (re, replacement)| re.replace_all(&acc, regex::NoExpand(replacement.as_str())).into_owned())
+}
+
+/// Section names that can be suffixed with a `[revision]` tag and are grouped per-revision;
+/// everything else (`FILE`, `TEST`, `NORMALIZE`) is shared across all of a file's revisions.
+const REVISIONABLE_SECTIONS: [&str; 6] =
+    ["EXPECT", "EXPECT_AST", "EXPECT_TOKENS", "EXPECT_ERROR", "EXPECT_EXIT", "ARGS"];
+
 fn parse_santat_file(content: &str) -> Result<SantatFile, Box<dyn std::error::Error>> {
     let content = normalize_newlines(content);
     let lines: Vec<&str> = content.lines().collect();
-    
-    let section_re = regex::Regex::new(r"^--([A-Z_]+)--\s*$")?;
-    
+
+    let section_re = regex::Regex::new(r"^--([A-Z_]+)(?:\[([A-Za-z0-9_-]+)\])?--\s*$")?;
+
     let mut blocks = Vec::new();
-    let mut current_name: Option<String> = None;
+    let mut current: Option<(String, Option<String>)> = None;
     let mut buffer = Vec::new();
 
     for line in lines {
         if let Some(captures) = section_re.captures(line) {
-            if let Some(name) = current_name {
+            if let Some((name, revision)) = current {
                 blocks.push(TestBlock {
                     name,
+                    revision,
                     content: buffer.join("\n"),
                 });
             }
-            current_name = Some(captures[1].to_string());
+            current = Some((
+                captures[1].to_string(),
+                captures.get(2).map(|m| m.as_str().to_string()),
+            ));
             buffer.clear();
         } else {
             buffer.push(line);
         }
     }
 
-    if let Some(name) = current_name {
+    if let Some((name, revision)) = current {
         blocks.push(TestBlock {
             name,
+            revision,
             content: buffer.join("\n"),
         });
     }
 
     let map: HashMap<String, String> = blocks
         .iter()
+        .filter(|b| b.revision.is_none())
         .map(|b| (b.name.clone(), b.content.clone()))
         .collect();
 
@@ -221,13 +407,46 @@ fn parse_santat_file(content: &str) -> Result<SantatFile, Box<dyn std::error::Er
         return Err("Missing required --FILE-- section".into());
     }
 
-    Ok(SantatFile { blocks, map })
+    let mut revision_order: Vec<Option<String>> = Vec::new();
+    let mut revision_map: HashMap<Option<String>, Revision> = HashMap::new();
+
+    for block in &blocks {
+        if !REVISIONABLE_SECTIONS.contains(&block.name.as_str()) {
+            continue;
+        }
+        let key = block.revision.clone();
+        let revision = revision_map.entry(key.clone()).or_insert_with(|| {
+            revision_order.push(key.clone());
+            Revision { name: key, args: Vec::new(), expects: HashMap::new() }
+        });
+        if block.name == "ARGS" {
+            revision.args = block.content.split_whitespace().map(|s| s.to_string()).collect();
+        } else {
+            revision.expects.insert(block.name.clone(), block.content.clone());
+        }
+    }
+
+    // A file with no bracketed sections still runs as a single unnamed revision.
+    if revision_order.is_empty() {
+        revision_order.push(None);
+        revision_map.insert(None, Revision { name: None, args: Vec::new(), expects: HashMap::new() });
+    }
+
+    let revisions = revision_order
+        .into_iter()
+        .map(|key| revision_map.remove(&key).unwrap())
+        .collect();
+
+    Ok(SantatFile { blocks, map, revisions })
 }
 
 fn stringify_santat_blocks(blocks: &[TestBlock]) -> String {
     blocks
         .iter()
-        .map(|b| format!("--{}--\n{}", b.name, b.content))
+        .map(|b| match &b.revision {
+            Some(revision) => format!("--{}[{}]--\n{}", b.name, revision, b.content),
+            None => format!("--{}--\n{}", b.name, b.content),
+        })
         .collect::<Vec<_>>()
         .join("\n")
 }
@@ -251,6 +470,106 @@ fn create_unified_diff(expected: &str, actual: &str, expected_label: &str, actua
     result.join("\n")
 }
 
+/// Builds a JUnit XML `<testsuite>` document, one `<testcase>` per `.santat` file; a failing
+/// file's failing checks are folded into a single `<failure>` so the diffs survive as its body.
+fn render_junit_report(results: &[TestResult]) -> String {
+    let total = results.len();
+    let failures = results.iter().filter(|r| !r.passed).count();
+    let total_secs: f64 = results.iter().map(|r| r.duration.as_secs_f64()).sum();
+
+    let mut cases = String::new();
+    for result in results {
+        let name = html_escape::encode_double_quoted_attribute(&result.name);
+        let classname = html_escape::encode_double_quoted_attribute(&result.path.display().to_string());
+        let time = result.duration.as_secs_f64();
+
+        if result.passed {
+            cases.push_str(&format!(
+                "  <testcase name=\"{name}\" classname=\"{classname}\" time=\"{time}\"/>\n"
+            ));
+            continue;
+        }
+
+        let failing: Vec<&CheckResult> = result.checks.iter().filter(|c| !c.passed).collect();
+        let message = failing
+            .iter()
+            .map(|c| if c.timed_out { format!("{} timed out", c.kind) } else { format!("{} differs", c.kind) })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let body = failing.iter().filter_map(|c| c.diff.as_deref()).collect::<Vec<_>>().join("\n\n");
+
+        cases.push_str(&format!(
+            "  <testcase name=\"{name}\" classname=\"{classname}\" time=\"{time}\">\n    <failure message=\"{message}\">{body}</failure>\n  </testcase>\n",
+            message = html_escape::encode_double_quoted_attribute(&message),
+            body = html_escape::encode_text(&body),
+        ));
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuite name=\"santa-test\" tests=\"{total}\" failures=\"{failures}\" time=\"{total_secs}\">\n{cases}</testsuite>\n"
+    )
+}
+
+/// Builds a JSON array, one object per `.santat` file, with its duration and per-check
+/// pass/fail and diffs for CI tooling to ingest.
+fn render_json_report(results: &[TestResult]) -> serde_json::Value {
+    serde_json::Value::Array(
+        results
+            .iter()
+            .map(|result| {
+                serde_json::json!({
+                    "name": result.name,
+                    "path": result.path.display().to_string(),
+                    "passed": result.passed,
+                    "duration_secs": result.duration.as_secs_f64(),
+                    "checks": result.checks.iter().map(|c| serde_json::json!({
+                        "kind": c.kind,
+                        "revision": c.revision,
+                        "passed": c.passed,
+                        "timed_out": c.timed_out,
+                        "diff": c.diff,
+                    })).collect::<Vec<_>>(),
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Escapes `%`, CR and LF per the GitHub Actions workflow-command rules, for the message position.
+fn escape_workflow_command(text: &str) -> String {
+    text.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escapes a property value (`file=`, `title=`): the message escapes plus `:` and `,`.
+fn escape_workflow_property(text: &str) -> String {
+    escape_workflow_command(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Prints a `::error file=...,title=...::...` annotation per failing check so GitHub surfaces
+/// it inline on the PR diff, pointing at the offending `.santat` file.
+fn print_github_annotations(results: &[TestResult]) {
+    for result in results {
+        if result.passed {
+            continue;
+        }
+        let file = escape_workflow_property(&result.path.display().to_string());
+        for check in result.checks.iter().filter(|c| !c.passed) {
+            let title = if check.timed_out { format!("{} timed out", check.kind) } else { format!("{} differs", check.kind) };
+            let title = match &check.revision {
+                Some(revision) => format!("{title} [{revision}]"),
+                None => title,
+            };
+            let message = check.diff.as_deref().unwrap_or(&title);
+            println!(
+                "::error file={file},title={title}::{message}",
+                file = file,
+                title = escape_workflow_property(&title),
+                message = escape_workflow_command(message),
+            );
+        }
+    }
+}
+
 fn run_command(runner: &Runner, args: &[String], timeout_secs: u64) -> Result<(i32, String, String, bool), Box<dyn std::error::Error>> {
     let mut cmd = runner.command(args);
     cmd.stdout(Stdio::piped())
@@ -302,74 +621,82 @@ fn collect_santat_files_recursive(dir: &Path, files: &mut Vec<PathBuf>) {
     }
 }
 
+/// `updates` is `(revision, section, actual)` — the revision a failing check ran under (`None`
+/// for the unnamed revision) and the section it belongs to, so `--update` rewrites the matching
+/// `--EXPECT[name]--` block rather than the first `--EXPECT--` it finds.
 fn update_santat_file_on_failures(
-    path: &Path, 
-    failures: &HashMap<&str, &str>
+    path: &Path,
+    updates: &[(Option<String>, &str, String)],
 ) -> Result<bool, Box<dyn std::error::Error>> {
     let original = fs::read_to_string(path)?;
     let mut santat = parse_santat_file(&original)?;
-    
-    let section_map = [
-        ("expect", "EXPECT"),
-        ("expect_ast", "EXPECT_AST"), 
-        ("expect_tokens", "EXPECT_TOKENS"),
-    ];
-    
+
     let mut changed = false;
-    
-    for (key, actual) in failures {
-        if let Some((_, section)) = section_map.iter().find(|(k, _)| k == key) {
-            for block in &mut santat.blocks {
-                if block.name == *section {
-                    let normalized_actual = normalize_newlines(actual);
-                    if normalize_newlines(&block.content) != normalized_actual {
-                        block.content = normalized_actual;
-                        changed = true;
-                    }
+
+    for (revision, section, actual) in updates {
+        let normalized_actual = normalize_newlines(actual);
+        for block in &mut santat.blocks {
+            if block.name == *section && block.revision == *revision {
+                if normalize_newlines(&block.content) != normalized_actual {
+                    block.content = normalized_actual;
+                    changed = true;
                 }
             }
         }
     }
-    
+
     if changed {
         fs::write(path, stringify_santat_blocks(&santat.blocks))?;
     }
-    
+
     Ok(changed)
 }
 
 fn run_one_test_parallel(runner: &Runner, test_path: &Path, timeout_secs: u64, do_update: bool) -> TestResult {
+    let start = Instant::now();
     let mut output_lines = Vec::new();
-    
+    let file_name = test_path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| test_path.display().to_string());
+
     let raw_content = match fs::read_to_string(test_path) {
         Ok(content) => content,
         Err(e) => {
             output_lines.push(format!("Error reading {}: {}", test_path.display(), e));
             return TestResult {
+                name: file_name,
+                path: test_path.to_path_buf(),
                 passed: false,
+                duration: start.elapsed(),
+                checks: Vec::new(),
                 output: output_lines.join("\n"),
             };
         }
     };
-    
+
     let santat = match parse_santat_file(&raw_content) {
         Ok(s) => s,
         Err(e) => {
             output_lines.push(format!("Error parsing {}: {}", test_path.display(), e));
             return TestResult {
+                name: file_name,
+                path: test_path.to_path_buf(),
                 passed: false,
+                duration: start.elapsed(),
+                checks: Vec::new(),
                 output: output_lines.join("\n"),
             };
         }
     };
-    
+
     let title = santat.map.get("TEST")
         .map(|s| s.trim())
         .filter(|s| !s.is_empty())
-        .unwrap_or_else(|| test_path.file_name().unwrap().to_str().unwrap());
-    
+        .unwrap_or_else(|| test_path.file_name().unwrap().to_str().unwrap())
+        .to_string();
+
     output_lines.push(format!("• {} ({})", title, test_path.display()));
-    
+
     let temp_dir = match (|| -> std::io::Result<TempDir> {
         let cwd = env::current_dir()?;
         TempDir::new_in(cwd)
@@ -378,85 +705,141 @@ fn run_one_test_parallel(runner: &Runner, test_path: &Path, timeout_secs: u64, d
         Err(e) => {
             output_lines.push(format!("Error creating temp dir: {}", e));
             return TestResult {
+                name: title,
+                path: test_path.to_path_buf(),
                 passed: false,
+                duration: start.elapsed(),
+                checks: Vec::new(),
                 output: output_lines.join("\n"),
             };
         }
     };
-    
+
     let temp_file = temp_dir.path().join("test.santa");
     if let Err(e) = fs::write(&temp_file, santat.map.get("FILE").unwrap_or(&String::new())) {
         output_lines.push(format!("Error writing temp file: {}", e));
         return TestResult {
+            name: title,
+            path: test_path.to_path_buf(),
             passed: false,
+            duration: start.elapsed(),
+            checks: Vec::new(),
             output: output_lines.join("\n"),
         };
     }
-    
+
+    let normalize_rules = match build_normalize_rules(temp_dir.path(), santat.map.get("NORMALIZE").map(|s| s.as_str())) {
+        Ok(rules) => rules,
+        Err(e) => {
+            output_lines.push(format!("Error parsing --NORMALIZE-- rules: {}", e));
+            return TestResult {
+                name: title,
+                path: test_path.to_path_buf(),
+                passed: false,
+                duration: start.elapsed(),
+                checks: Vec::new(),
+                output: output_lines.join("\n"),
+            };
+        }
+    };
+
     let mut any_fail = false;
-    let mut actuals = HashMap::new();
-    let mut failed = HashMap::new();
-    
-    let checks = [
-        ("output", "EXPECT", vec![temp_file.to_str().unwrap()]),
-        ("ast", "EXPECT_AST", vec!["ast", temp_file.to_str().unwrap()]),
-        ("tokens", "EXPECT_TOKENS", vec!["tokens", temp_file.to_str().unwrap()]),
+    let mut updates: Vec<(Option<String>, &str, String)> = Vec::new();
+    let mut checks: Vec<CheckResult> = Vec::new();
+
+    let base_checks: [(&str, &str, &[&str], CheckField); 5] = [
+        ("output", "EXPECT", &[], CheckField::Stdout),
+        ("ast", "EXPECT_AST", &["ast"], CheckField::Stdout),
+        ("tokens", "EXPECT_TOKENS", &["tokens"], CheckField::Stdout),
+        ("stderr", "EXPECT_ERROR", &[], CheckField::Stderr),
+        ("exit code", "EXPECT_EXIT", &[], CheckField::ExitCode),
     ];
-    
-    for (kind, expect_key, args) in checks {
-        if !santat.map.contains_key(expect_key) {
-            continue;
+
+    for revision in &santat.revisions {
+        if let Some(name) = &revision.name {
+            output_lines.push(format!("  ▸ [{}]", name));
         }
-        
-        let expected = santat.map.get(expect_key).unwrap();
-        let args_vec: Vec<String> = args.iter().map(|s| s.to_string()).collect();
-        
-        match run_command(runner, &args_vec, timeout_secs) {
-            Ok((_, stdout, _, timed_out)) => {
-                if timed_out {
-                    output_lines.push(format!("    ✗ {} timed out after {}s", kind, timeout_secs));
-                    any_fail = true;
-                    continue;
+
+        for (kind, expect_key, mode_args, field) in base_checks {
+            if !revision.expects.contains_key(expect_key) {
+                continue;
+            }
+
+            let expected = revision.expects.get(expect_key).unwrap();
+            let mut args_vec: Vec<String> = mode_args.iter().map(|s| s.to_string()).collect();
+            args_vec.push(temp_file.to_str().unwrap().to_string());
+            args_vec.extend(revision.args.iter().cloned());
+
+            match run_command(runner, &args_vec, timeout_secs) {
+                Ok((code, stdout, stderr, timed_out)) => {
+                    if timed_out {
+                        output_lines.push(format!("    ✗ {} timed out after {}s", kind, timeout_secs));
+                        any_fail = true;
+                        checks.push(CheckResult {
+                            kind: expect_key.to_string(),
+                            revision: revision.name.clone(),
+                            passed: false,
+                            timed_out: true,
+                            diff: None,
+                        });
+                        continue;
+                    }
+
+                    let actual = match field {
+                        CheckField::Stdout => stdout,
+                        CheckField::Stderr => stderr,
+                        CheckField::ExitCode => code.to_string(),
+                    };
+                    let normalized_expected = apply_normalization(expected, &normalize_rules);
+                    let normalized_actual = apply_normalization(&actual, &normalize_rules);
+
+                    if normalize_newlines(&normalized_expected) == normalize_newlines(&normalized_actual) {
+                        output_lines.push(format!("    ✓ {} matches", kind));
+                        checks.push(CheckResult {
+                            kind: expect_key.to_string(),
+                            revision: revision.name.clone(),
+                            passed: true,
+                            timed_out: false,
+                            diff: None,
+                        });
+                    } else {
+                        output_lines.push(format!("    ✗ {} differs", kind));
+                        let diff = create_unified_diff(
+                            &normalized_expected,
+                            &normalized_actual,
+                            &format!("{} expected", kind),
+                            &format!("{} actual", kind),
+                            &Colors::new(false) // Use no-color for stored output
+                        );
+                        output_lines.push(diff.clone());
+                        any_fail = true;
+                        checks.push(CheckResult {
+                            kind: expect_key.to_string(),
+                            revision: revision.name.clone(),
+                            passed: false,
+                            timed_out: false,
+                            diff: Some(diff),
+                        });
+                        updates.push((revision.name.clone(), expect_key, normalized_actual));
+                    }
                 }
-                
-                actuals.insert(expect_key, stdout.clone());
-                
-                if normalize_newlines(expected) == normalize_newlines(&stdout) {
-                    output_lines.push(format!("    ✓ {} matches", kind));
-                } else {
-                    output_lines.push(format!("    ✗ {} differs", kind));
-                    let diff = create_unified_diff(
-                        expected, 
-                        &stdout, 
-                        &format!("{} expected", kind),
-                        &format!("{} actual", kind),
-                        &Colors::new(false) // Use no-color for stored output
-                    );
-                    output_lines.push(diff);
+                Err(e) => {
+                    output_lines.push(format!("    ✗ {} failed: {}", kind, e));
                     any_fail = true;
-                    failed.insert(expect_key, true);
+                    checks.push(CheckResult {
+                        kind: expect_key.to_string(),
+                        revision: revision.name.clone(),
+                        passed: false,
+                        timed_out: false,
+                        diff: Some(format!("error running test: {}", e)),
+                    });
                 }
             }
-            Err(e) => {
-                output_lines.push(format!("    ✗ {} failed: {}", kind, e));
-                any_fail = true;
-            }
         }
     }
-    
-    if do_update && any_fail {
-        let mut changes = HashMap::new();
-        if failed.contains_key("EXPECT") {
-            changes.insert("expect", actuals.get("EXPECT").map(|s| s.as_str()).unwrap_or(""));
-        }
-        if failed.contains_key("EXPECT_AST") {
-            changes.insert("expect_ast", actuals.get("EXPECT_AST").map(|s| s.as_str()).unwrap_or(""));
-        }
-        if failed.contains_key("EXPECT_TOKENS") {
-            changes.insert("expect_tokens", actuals.get("EXPECT_TOKENS").map(|s| s.as_str()).unwrap_or(""));
-        }
-        
-        match update_santat_file_on_failures(test_path, &changes) {
+
+    if do_update && !updates.is_empty() {
+        match update_santat_file_on_failures(test_path, &updates) {
             Ok(true) => {
                 output_lines.push(format!("  UPDATED {}", test_path.file_name().unwrap().to_str().unwrap()));
             }
@@ -473,7 +856,14 @@ fn run_one_test_parallel(runner: &Runner, test_path: &Path, timeout_secs: u64, d
         output_lines.push("  FAIL".to_string());
     }
     
-    TestResult { passed: !any_fail, output: output_lines.join("\n") }
+    TestResult {
+        name: title,
+        path: test_path.to_path_buf(),
+        passed: !any_fail,
+        duration: start.elapsed(),
+        checks,
+        output: output_lines.join("\n"),
+    }
 }
 
 // note: a non-parallel version existed earlier but was unused; the parallel path prints results coherently
@@ -504,51 +894,259 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         eprintln!("No .santat files found in the provided paths.");
         std::process::exit(2);
     }
-    
-    // Run tests in parallel
-    let results: Vec<TestResult> = all_files
+
+    if let Some(seed) = args.shuffle {
+        let seed = seed.unwrap_or_else(random_seed);
+        println!("Shuffle seed: {}", seed);
+        shuffle_with_seed(&mut all_files, seed);
+    }
+
+    if args.watch {
+        return watch_loop(&runner, &args, all_files, &colors, use_color);
+    }
+
+    let results = run_all(&runner, &all_files, args.timeout, args.update);
+    let fail_count = report_results(&results, all_files.len(), &colors, use_color, &args)?;
+
+    std::process::exit(if fail_count == 0 { 0 } else { 1 });
+}
+
+/// Run every `.santat` file in `files` in parallel, in the order given.
+/// Rayon's `collect` preserves input order, so the returned `Vec` lines up with `files`.
+fn run_all(runner: &Runner, files: &[PathBuf], timeout: u64, update: bool) -> Vec<TestResult> {
+    files
         .par_iter()
-        .map(|file| run_one_test_parallel(&runner, file, args.timeout, args.update))
-        .collect();
-    
-    // Output results in original order with colors
-    let mut pass_count = 0;
-    for result in &results {
-        // Apply colors to the output for display
-        let colored_output = if use_color {
-            result.output
-                .replace("✓", &(colors.green)("✓"))
-                .replace("✗", &(colors.red)("✗"))
-                .replace("PASS", &(colors.green)("PASS"))
-                .replace("FAIL", &(colors.red)("FAIL"))
-                .replace("UPDATED", &(colors.cyan)("UPDATED"))
-        } else {
-            result.output.clone()
+        .map(|file| run_one_test_parallel(runner, file, timeout, update))
+        .collect()
+}
+
+/// Print/write `results` via the configured reporter and return the number of failing tests.
+fn report_results(
+    results: &[TestResult],
+    total_files: usize,
+    colors: &Colors,
+    use_color: bool,
+    args: &Args,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let pass_count = results.iter().filter(|r| r.passed).count();
+    let fail_count = total_files - pass_count;
+
+    match args.reporter {
+        Reporter::Pretty => {
+            // Output results in original order with colors
+            for result in results {
+                // Apply colors to the output for display
+                let colored_output = if use_color {
+                    result.output
+                        .replace("✓", &(colors.green)("✓"))
+                        .replace("✗", &(colors.red)("✗"))
+                        .replace("PASS", &(colors.green)("PASS"))
+                        .replace("FAIL", &(colors.red)("FAIL"))
+                        .replace("UPDATED", &(colors.cyan)("UPDATED"))
+                } else {
+                    result.output.clone()
+                };
+
+                println!("{}", colored_output);
+                println!(); // Add spacing between tests
+            }
+
+            println!("{}: {}/{} passing, {} failing",
+                (colors.bold)("Summary"),
+                pass_count,
+                total_files,
+                fail_count
+            );
+        }
+        Reporter::Junit => write_reporter_output(&args.reporter_output, &render_junit_report(results))?,
+        Reporter::Json => {
+            let json = serde_json::to_string_pretty(&render_json_report(results))?;
+            write_reporter_output(&args.reporter_output, &json)?;
+        }
+    }
+
+    if args.github_annotations || env::var_os("GITHUB_ACTIONS").is_some() {
+        print_github_annotations(results);
+    }
+
+    Ok(fail_count)
+}
+
+fn write_reporter_output(path: &Option<PathBuf>, content: &str) -> Result<(), Box<dyn std::error::Error>> {
+    match path {
+        Some(path) => fs::write(path, content)?,
+        None => println!("{}", content),
+    }
+    Ok(())
+}
+
+/// Clear the terminal between watch runs, same escape sequence used by most watch-mode CLIs.
+fn clear_screen() {
+    print!("\x1b[2J\x1b[1;1H");
+}
+
+/// Modification time of every file in `files`, skipping any that can't be stat'd.
+fn collect_mtimes(files: &[PathBuf]) -> HashMap<PathBuf, SystemTime> {
+    files
+        .iter()
+        .filter_map(|f| fs::metadata(f).and_then(|m| m.modified()).ok().map(|m| (f.clone(), m)))
+        .collect()
+}
+
+/// `--watch`: run once, then poll the targets (and a local --bin) for changes, debouncing
+/// rapid saves, and re-run only what changed. Never returns on its own; the user exits via
+/// Ctrl+C, matching the watch mode of mature CLI test tools (e.g. `cargo watch`, `jest --watch`).
+fn watch_loop(
+    runner: &Runner,
+    args: &Args,
+    mut all_files: Vec<PathBuf>,
+    colors: &Colors,
+    use_color: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    const POLL_INTERVAL: Duration = Duration::from_millis(300);
+    const DEBOUNCE: Duration = Duration::from_millis(200);
+
+    let local_bin = match runner {
+        Runner::Local(path) => Some(path.clone()),
+        Runner::Docker { .. } => None,
+    };
+    let bin_mtime = |path: &PathBuf| fs::metadata(path).and_then(|m| m.modified()).ok();
+
+    let mut file_mtimes = collect_mtimes(&all_files);
+    let mut last_bin_mtime = local_bin.as_ref().and_then(bin_mtime);
+
+    println!("Watching {} test file(s) for changes. Press Ctrl+C to stop.\n", all_files.len());
+    let results = run_all(runner, &all_files, args.timeout, args.update);
+    report_results(&results, all_files.len(), colors, use_color, args)?;
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let mut current_files = Vec::new();
+        for target in &args.targets {
+            current_files.extend(collect_santat_files(target));
+        }
+
+        let bin_rebuilt = match (&local_bin, last_bin_mtime) {
+            (Some(path), last) => {
+                let current = bin_mtime(path);
+                last_bin_mtime = current;
+                current.is_some() && current != last
+            }
+            (None, _) => false,
         };
-        
-        println!("{}", colored_output);
-        println!(); // Add spacing between tests
-        
-        if result.passed {
-            pass_count += 1;
+
+        let current_mtimes = collect_mtimes(&current_files);
+        let changed_files: Vec<PathBuf> = current_files
+            .iter()
+            .filter(|f| current_mtimes.get(*f) != file_mtimes.get(*f))
+            .cloned()
+            .collect();
+        let files_added_or_removed = current_files.len() != all_files.len();
+
+        if !bin_rebuilt && changed_files.is_empty() && !files_added_or_removed {
+            continue;
         }
+
+        // Debounce: give a rapid sequence of saves (or the linker finishing) time to settle.
+        thread::sleep(DEBOUNCE);
+
+        all_files = current_files;
+        file_mtimes = collect_mtimes(&all_files);
+
+        clear_screen();
+        let (run_files, reason): (Vec<PathBuf>, &str) = if bin_rebuilt || files_added_or_removed {
+            (all_files.clone(), "rebuilding and re-running the full suite")
+        } else {
+            (changed_files, "re-running changed tests")
+        };
+        println!("{} file(s) changed, {}...\n", run_files.len(), reason);
+
+        let results = run_all(runner, &run_files, args.timeout, args.update);
+        report_results(&results, run_files.len(), colors, use_color, args)?;
     }
-    
-    let fail_count = all_files.len() - pass_count;
-    println!("{}: {}/{} passing, {} failing",
-        (colors.bold)("Summary"),
-        pass_count,
-        all_files.len(),
-        fail_count
-    );
-    
-    std::process::exit(if fail_count == 0 { 0 } else { 1 });
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn sample_results() -> Vec<TestResult> {
+        vec![
+            TestResult {
+                name: "passing".to_string(),
+                path: PathBuf::from("tests/passing.santat"),
+                passed: true,
+                duration: Duration::from_millis(5),
+                checks: vec![CheckResult {
+                    kind: "EXPECT".to_string(),
+                    revision: None,
+                    passed: true,
+                    timed_out: false,
+                    diff: None,
+                }],
+                output: String::new(),
+            },
+            TestResult {
+                name: "failing".to_string(),
+                path: PathBuf::from("tests/failing.santat"),
+                passed: false,
+                duration: Duration::from_millis(10),
+                checks: vec![CheckResult {
+                    kind: "EXPECT".to_string(),
+                    revision: Some("release".to_string()),
+                    passed: false,
+                    timed_out: false,
+                    diff: Some("--- EXPECT expected\n+++ EXPECT actual\n-hi\n+bye".to_string()),
+                }],
+                output: String::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_render_junit_report() {
+        let xml = render_junit_report(&sample_results());
+        assert!(xml.contains(r#"<testsuite name="santa-test" tests="2" failures="1""#));
+        assert!(xml.contains(r#"<testcase name="passing" classname="tests/passing.santat" time="0.005"/>"#));
+        assert!(xml.contains(r#"<failure message="EXPECT differs">"#));
+        assert!(xml.contains("-hi"));
+    }
+
+    #[test]
+    fn test_render_json_report() {
+        let json = render_json_report(&sample_results());
+        assert_eq!(json[0]["name"], "passing");
+        assert_eq!(json[0]["passed"], true);
+        assert_eq!(json[1]["checks"][0]["revision"], "release");
+        assert_eq!(json[1]["checks"][0]["passed"], false);
+    }
+
+    #[test]
+    fn test_escape_workflow_command() {
+        assert_eq!(escape_workflow_command("100% done\nnext line"), "100%25 done%0Anext line");
+        assert_eq!(escape_workflow_command("no special chars"), "no special chars");
+    }
+
+    #[test]
+    fn test_shuffle_with_seed_is_deterministic() {
+        let mut a: Vec<i32> = (0..10).collect();
+        let mut b: Vec<i32> = (0..10).collect();
+        shuffle_with_seed(&mut a, 42);
+        shuffle_with_seed(&mut b, 42);
+        assert_eq!(a, b);
+        assert_ne!(a, (0..10).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_shuffle_with_seed_different_seeds_differ() {
+        let mut a: Vec<i32> = (0..20).collect();
+        let mut b: Vec<i32> = (0..20).collect();
+        shuffle_with_seed(&mut a, 1);
+        shuffle_with_seed(&mut b, 2);
+        assert_ne!(a, b);
+    }
+
     #[test]
     fn test_parse_santat_file() {
         let content = r#"--FILE--
@@ -580,6 +1178,62 @@ hello world"#;
         assert!(result.unwrap_err().to_string().contains("Missing required --FILE-- section"));
     }
 
+    #[test]
+    fn test_parse_santat_file_error_and_exit_sections() {
+        let content = r#"--FILE--
+1 / 0
+
+--EXPECT_ERROR--
+division by zero
+
+--EXPECT_EXIT--
+1"#;
+
+        let result = parse_santat_file(content).unwrap();
+        assert_eq!(result.map["EXPECT_ERROR"], "division by zero\n");
+        assert_eq!(result.map["EXPECT_EXIT"], "1");
+    }
+
+    #[test]
+    fn test_parse_santat_file_revisions() {
+        let content = r#"--FILE--
+print("hi")
+
+--EXPECT[release]--
+hi
+
+--ARGS[release]--
+--release
+
+--EXPECT[trace]--
+hi (traced)"#;
+
+        let result = parse_santat_file(content).unwrap();
+        assert_eq!(result.revisions.len(), 2);
+
+        let release = result.revisions.iter().find(|r| r.name.as_deref() == Some("release")).unwrap();
+        assert_eq!(release.expects["EXPECT"], "hi\n");
+        assert_eq!(release.args, vec!["--release".to_string()]);
+
+        let trace = result.revisions.iter().find(|r| r.name.as_deref() == Some("trace")).unwrap();
+        assert_eq!(trace.expects["EXPECT"], "hi (traced)");
+        assert!(trace.args.is_empty());
+    }
+
+    #[test]
+    fn test_parse_santat_file_defaults_to_single_unnamed_revision() {
+        let content = r#"--FILE--
+print("hi")
+
+--EXPECT--
+hi"#;
+
+        let result = parse_santat_file(content).unwrap();
+        assert_eq!(result.revisions.len(), 1);
+        assert_eq!(result.revisions[0].name, None);
+        assert_eq!(result.revisions[0].expects["EXPECT"], "hi");
+    }
+
     #[test]
     fn test_normalize_newlines() {
         assert_eq!(normalize_newlines("hello\r\nworld"), "hello\nworld");
@@ -587,21 +1241,50 @@ hello world"#;
         assert_eq!(normalize_newlines("hello world"), "hello world");
     }
 
+    #[test]
+    fn test_apply_normalization_built_in_rules() {
+        let temp_dir = PathBuf::from("/tmp/santa-test-abc123");
+        let rules = build_normalize_rules(&temp_dir, None).unwrap();
+
+        assert_eq!(
+            apply_normalization("loaded /tmp/santa-test-abc123/test.santa", &rules),
+            "loaded $TEST_DIR/test.santa"
+        );
+        assert_eq!(
+            apply_normalization("segfault at 0x7fff5fbff8c0", &rules),
+            "segfault at 0xADDR"
+        );
+        assert_eq!(
+            apply_normalization(r"C:\temp\test.santa", &rules),
+            "C:/temp/test.santa"
+        );
+    }
+
+    #[test]
+    fn test_apply_normalization_custom_rules() {
+        let temp_dir = PathBuf::from("/tmp/santa-test-xyz");
+        let rules = build_normalize_rules(&temp_dir, Some("took \\d+ms => took $DURATION")).unwrap();
+
+        assert_eq!(apply_normalization("took 42ms", &rules), "took $DURATION");
+    }
+
     #[test]
     fn test_stringify_santat_blocks() {
         let blocks = vec![
             TestBlock {
                 name: "FILE".to_string(),
+                revision: None,
                 content: "print(\"test\")".to_string(),
             },
             TestBlock {
                 name: "EXPECT".to_string(),
+                revision: Some("release".to_string()),
                 content: "test".to_string(),
             },
         ];
 
         let result = stringify_santat_blocks(&blocks);
-        assert_eq!(result, "--FILE--\nprint(\"test\")\n--EXPECT--\ntest");
+        assert_eq!(result, "--FILE--\nprint(\"test\")\n--EXPECT[release]--\ntest");
     }
 
     #[test]